@@ -0,0 +1,49 @@
+//! Compares `CopyRange::len` for unsigned integers, which computes the length
+//! of a non-empty range with a single `wrapping_sub`, with `Range::len`.
+
+use std::hint::black_box;
+
+use copy_range::CopyRange;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// Returns a mix of well-formed, empty and reversed ranges, generated
+/// deterministically so that runs are comparable.
+fn ranges(count: u32) -> Vec<CopyRange<u32>> {
+    (0..count)
+        .map(|i| {
+            let start = i.wrapping_mul(2_654_435_761) % 4096;
+            let end = i.wrapping_mul(40_503).rotate_left(7) % 4096;
+            CopyRange { start, end }
+        })
+        .collect()
+}
+
+fn len(c: &mut Criterion) {
+    let mut group = c.benchmark_group("len");
+
+    let ranges = ranges(4096);
+    group.bench_function("CopyRange<u32>::len", |b| {
+        b.iter(|| black_box(&ranges).iter().map(|r| r.len()).sum::<usize>())
+    });
+    group.bench_function("Range<u32>::len", |b| {
+        b.iter(|| {
+            black_box(&ranges).iter().map(|r| r.into_std().len()).sum::<usize>()
+        })
+    });
+
+    let ranges: Vec<CopyRange<usize>> =
+        ranges.iter().map(|r| r.map(|x| x as usize)).collect();
+    group.bench_function("CopyRange<usize>::len", |b| {
+        b.iter(|| black_box(&ranges).iter().map(|r| r.len()).sum::<usize>())
+    });
+    group.bench_function("Range<usize>::len", |b| {
+        b.iter(|| {
+            black_box(&ranges).iter().map(|r| r.into_std().len()).sum::<usize>()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, len);
+criterion_main!(benches);
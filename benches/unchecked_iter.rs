@@ -0,0 +1,49 @@
+//! Compares iterating over a `CopyRange<usize>` with `unchecked_iter`, which
+//! stops when `start == end`, with iterating over the equivalent `Range`.
+
+use std::hint::black_box;
+
+use copy_range::CopyRange;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn unchecked_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iter");
+    let data: Vec<u64> = (0..4096).collect();
+
+    // Summing the elements themselves, which the compiler may vectorize or
+    // turn into a closed form.
+    let range = CopyRange { start: 0, end: 4096usize };
+    group.bench_function("CopyRange<usize>::unchecked_iter sum", |b| {
+        b.iter(|| black_box(range).unchecked_iter().sum::<usize>())
+    });
+    group.bench_function("Range<usize> sum", |b| {
+        b.iter(|| black_box(range).into_std().sum::<usize>())
+    });
+
+    // Indexing a slice with each element, as in a hot loop over indices.
+    group.bench_function("CopyRange<usize>::unchecked_iter index", |b| {
+        b.iter(|| {
+            let data = black_box(&data[..]);
+            let mut total = 0u64;
+            for i in black_box(range).unchecked_iter() {
+                total = total.wrapping_add(data[i]);
+            }
+            total
+        })
+    });
+    group.bench_function("Range<usize> index", |b| {
+        b.iter(|| {
+            let data = black_box(&data[..]);
+            let mut total = 0u64;
+            for i in black_box(range).into_std() {
+                total = total.wrapping_add(data[i]);
+            }
+            total
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, unchecked_iter);
+criterion_main!(benches);
@@ -0,0 +1,487 @@
+use core::{
+    fmt,
+    ops::{
+        Bound, Range, RangeBounds, RangeFrom, RangeInclusive, RangeTo,
+        RangeToInclusive,
+    },
+};
+
+use crate::{
+    CopyRange, CopyRangeFrom, CopyRangeFull, CopyRangeInclusive, CopyRangeTo,
+    CopyRangeToInclusive, IndexRangeError, slice::check_range,
+};
+
+/// Any one of the range types in this crate.
+///
+/// This is useful for storing ranges of different kinds together, e.g. ranges
+/// parsed from user input.
+///
+/// It can be converted from any of the range types in this crate or in
+/// `core::ops`, and implements [`RangeBounds`] with the same bounds as the
+/// range it was converted from:
+///
+/// ```rust
+/// # use copy_range::AnyCopyRange;
+/// use std::ops::{Bound, RangeBounds};
+/// fn bounds<R: RangeBounds<u64>>(range: &R) -> (Bound<&u64>, Bound<&u64>) {
+///     (range.start_bound(), range.end_bound())
+/// }
+/// assert_eq!(bounds(&AnyCopyRange::from(2..5)), bounds(&(2..5)));
+/// assert_eq!(bounds(&AnyCopyRange::from(2..=5)), bounds(&(2..=5)));
+/// assert_eq!(bounds(&AnyCopyRange::from(2..)), bounds(&(2..)));
+/// assert_eq!(bounds(&AnyCopyRange::from(..5)), bounds(&(..5)));
+/// assert_eq!(bounds(&AnyCopyRange::from(..=5)), bounds(&(..=5)));
+/// assert_eq!(
+///     bounds(&AnyCopyRange::<u64>::from(..)),
+///     bounds::<std::ops::RangeFull>(&..)
+/// );
+/// ```
+///
+/// It can be converted back into any of those range types with `TryFrom`,
+/// which succeeds if it is the corresponding variant, and otherwise returns
+/// the `AnyCopyRange` unchanged as the error:
+///
+/// ```rust
+/// # use copy_range::{AnyCopyRange, CopyRange, CopyRangeTo};
+/// use std::ops::{Range, RangeFull, RangeTo};
+/// let range = AnyCopyRange::from(2..5);
+/// assert_eq!(CopyRange::try_from(range), Ok(CopyRange { start: 2, end: 5 }));
+/// assert_eq!(Range::try_from(range), Ok(2..5));
+/// assert_eq!(RangeTo::try_from(range), Err(range));
+/// assert_eq!(RangeFull::try_from(range), Err(range));
+///
+/// let full = AnyCopyRange::<u32>::from(..);
+/// assert_eq!(RangeFull::try_from(full), Ok(..));
+/// assert_eq!(CopyRangeTo::try_from(full), Err(AnyCopyRange::Full));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnyCopyRange<Idx> {
+    /// An unbounded range (`..`).
+    Full,
+    /// A range only bounded inclusively below (`start..`).
+    From(CopyRangeFrom<Idx>),
+    /// A range only bounded exclusively above (`..end`).
+    To(CopyRangeTo<Idx>),
+    /// A range only bounded inclusively above (`..=end`).
+    ToInclusive(CopyRangeToInclusive<Idx>),
+    /// A range bounded inclusively below and exclusively above
+    /// (`start..end`).
+    Range(CopyRange<Idx>),
+    /// A range bounded inclusively below and above (`start..=end`).
+    Inclusive(CopyRangeInclusive<Idx>),
+}
+
+impl<Idx> AnyCopyRange<Idx> {
+    /// Creates the range with the given start and end bounds, if one of the
+    /// range types in this crate can represent it.
+    ///
+    /// The bounds map to variants as follows:
+    ///
+    /// | `start`       | `end`         | Variant                                    |
+    /// |---------------|---------------|--------------------------------------------|
+    /// | `Unbounded`   | `Unbounded`   | [`Full`](AnyCopyRange::Full)               |
+    /// | `Included(s)` | `Unbounded`   | [`From`](AnyCopyRange::From)               |
+    /// | `Unbounded`   | `Excluded(e)` | [`To`](AnyCopyRange::To)                   |
+    /// | `Unbounded`   | `Included(e)` | [`ToInclusive`](AnyCopyRange::ToInclusive) |
+    /// | `Included(s)` | `Excluded(e)` | [`Range`](AnyCopyRange::Range)             |
+    /// | `Included(s)` | `Included(e)` | [`Inclusive`](AnyCopyRange::Inclusive)     |
+    ///
+    /// None of the range types have an excluded start bound, so this returns
+    /// `None` if `start` is `Excluded`.
+    ///
+    /// ```rust
+    /// # use copy_range::{AnyCopyRange, CopyRange};
+    /// use std::ops::Bound;
+    /// assert_eq!(
+    ///     AnyCopyRange::from_bounds(Bound::Included(1), Bound::Excluded(5)),
+    ///     Some(AnyCopyRange::Range(CopyRange { start: 1, end: 5 })),
+    /// );
+    /// assert_eq!(
+    ///     AnyCopyRange::<i32>::from_bounds(Bound::Unbounded, Bound::Unbounded),
+    ///     Some(AnyCopyRange::Full),
+    /// );
+    /// assert_eq!(
+    ///     AnyCopyRange::from_bounds(Bound::Excluded(1), Bound::Unbounded),
+    ///     None
+    /// );
+    /// ```
+    pub fn from_bounds(start: Bound<Idx>, end: Bound<Idx>) -> Option<Self> {
+        Some(match (start, end) {
+            (Bound::Unbounded, Bound::Unbounded) => AnyCopyRange::Full,
+            (Bound::Included(start), Bound::Unbounded) => {
+                AnyCopyRange::From(CopyRangeFrom { start })
+            }
+            (Bound::Unbounded, Bound::Excluded(end)) => {
+                AnyCopyRange::To(CopyRangeTo { end })
+            }
+            (Bound::Unbounded, Bound::Included(end)) => {
+                AnyCopyRange::ToInclusive(CopyRangeToInclusive { end })
+            }
+            (Bound::Included(start), Bound::Excluded(end)) => {
+                AnyCopyRange::Range(CopyRange { start, end })
+            }
+            (Bound::Included(start), Bound::Included(end)) => {
+                AnyCopyRange::Inclusive(CopyRangeInclusive { start, end })
+            }
+            (Bound::Excluded(_), _) => return None,
+        })
+    }
+
+    /// Returns the start and end bounds of the range.
+    ///
+    /// This is the inverse of [`from_bounds`](Self::from_bounds), and is like
+    /// [`RangeBounds::start_bound`] and [`RangeBounds::end_bound`], but
+    /// returns owned bounds.
+    pub fn to_bounds(&self) -> (Bound<Idx>, Bound<Idx>)
+    where
+        Idx: Copy,
+    {
+        (self.start_bound().cloned(), self.end_bound().cloned())
+    }
+
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// See [`RangeBounds::contains`].
+    ///
+    /// ```rust
+    /// # use copy_range::AnyCopyRange;
+    /// let queries: Vec<AnyCopyRange<u64>> = vec![
+    ///     (2..5).into(),
+    ///     (2..=5).into(),
+    ///     (2..).into(),
+    ///     (..5).into(),
+    ///     (..=5).into(),
+    ///     (..).into(),
+    /// ];
+    /// let matching: Vec<usize> =
+    ///     (0..queries.len()).filter(|&i| queries[i].contains(&5)).collect();
+    /// assert_eq!(matching, [1, 2, 4, 5]);
+    /// ```
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        <Self as RangeBounds<Idx>>::contains(self, item)
+    }
+
+    /// Returns `true` if the range has a start bound, i.e. if it is a
+    /// [`From`](AnyCopyRange::From), [`Range`](AnyCopyRange::Range) or
+    /// [`Inclusive`](AnyCopyRange::Inclusive) range.
+    pub fn is_bounded_below(&self) -> bool {
+        matches!(
+            self,
+            AnyCopyRange::From(_)
+                | AnyCopyRange::Range(_)
+                | AnyCopyRange::Inclusive(_)
+        )
+    }
+
+    /// Returns `true` if the range has an end bound, i.e. if it is a
+    /// [`To`](AnyCopyRange::To), [`ToInclusive`](AnyCopyRange::ToInclusive),
+    /// [`Range`](AnyCopyRange::Range) or
+    /// [`Inclusive`](AnyCopyRange::Inclusive) range.
+    pub fn is_bounded_above(&self) -> bool {
+        matches!(
+            self,
+            AnyCopyRange::To(_)
+                | AnyCopyRange::ToInclusive(_)
+                | AnyCopyRange::Range(_)
+                | AnyCopyRange::Inclusive(_)
+        )
+    }
+
+    /// Borrows the endpoints of the range.
+    ///
+    /// The returned range implements [`RangeBounds<Idx>`].
+    pub fn as_ref(&self) -> AnyCopyRange<&Idx> {
+        match self {
+            AnyCopyRange::Full => AnyCopyRange::Full,
+            AnyCopyRange::From(range) => AnyCopyRange::From(range.as_ref()),
+            AnyCopyRange::To(range) => AnyCopyRange::To(range.as_ref()),
+            AnyCopyRange::ToInclusive(range) => {
+                AnyCopyRange::ToInclusive(range.as_ref())
+            }
+            AnyCopyRange::Range(range) => AnyCopyRange::Range(range.as_ref()),
+            AnyCopyRange::Inclusive(range) => {
+                AnyCopyRange::Inclusive(range.as_ref())
+            }
+        }
+    }
+}
+
+impl AnyCopyRange<usize> {
+    /// Converts the range into the equivalent `CopyRange` for a slice of
+    /// length `len`, or returns an error if the range is not valid for such a
+    /// slice.
+    ///
+    /// This does the same checks, in the same order, as indexing a slice with
+    /// the range, but returns an error instead of panicking. Errors report the
+    /// end of the range exclusively, i.e. as `end + 1` for inclusive ranges.
+    /// Use `.unwrap()` to panic on an invalid range instead.
+    ///
+    /// ```rust
+    /// # use copy_range::{AnyCopyRange, CopyRange, IndexRangeError};
+    /// use IndexRangeError::*;
+    /// let len = 5;
+    /// let ok = |start, end| Ok(CopyRange { start, end });
+    /// let cases: &[(
+    ///     AnyCopyRange<usize>,
+    ///     Result<CopyRange<usize>, IndexRangeError>,
+    /// )] = &[
+    ///     ((..).into(), ok(0, 5)),
+    ///     ((2..).into(), ok(2, 5)),
+    ///     ((len..).into(), ok(5, 5)),
+    ///     ((len + 1..).into(), Err(StartOutOfBounds { start: 6, len })),
+    ///     (
+    ///         (usize::MAX..).into(),
+    ///         Err(StartOutOfBounds { start: usize::MAX, len }),
+    ///     ),
+    ///     ((..len - 1).into(), ok(0, 4)),
+    ///     ((..len).into(), ok(0, 5)),
+    ///     ((..len + 1).into(), Err(EndOutOfBounds { end: 6, len })),
+    ///     ((..usize::MAX).into(), Err(EndOutOfBounds { end: usize::MAX, len })),
+    ///     ((..=len - 1).into(), ok(0, 5)),
+    ///     ((..=len).into(), Err(EndOutOfBounds { end: 6, len })),
+    ///     ((..=usize::MAX).into(), Err(EndOverflow)),
+    ///     ((1..len).into(), ok(1, 5)),
+    ///     ((1..len + 1).into(), Err(EndOutOfBounds { end: 6, len })),
+    ///     ((3..2).into(), Err(StartGreaterThanEnd { start: 3, end: 2 })),
+    ///     ((len + 1..len).into(), Err(StartGreaterThanEnd { start: 6, end: 5 })),
+    ///     (
+    ///         (usize::MAX..usize::MAX).into(),
+    ///         Err(EndOutOfBounds { end: usize::MAX, len }),
+    ///     ),
+    ///     ((1..=len - 1).into(), ok(1, 5)),
+    ///     ((1..=len).into(), Err(EndOutOfBounds { end: 6, len })),
+    ///     ((len..=len - 1).into(), ok(5, 5)),
+    ///     ((3..=1).into(), Err(StartGreaterThanEnd { start: 3, end: 2 })),
+    ///     ((0..=usize::MAX).into(), Err(EndOverflow)),
+    /// ];
+    /// for (range, expected) in cases {
+    ///     assert_eq!(range.resolve(len), *expected, "{range:?}");
+    /// }
+    ///
+    /// // The resolved range indexes the same elements as the original.
+    /// let data = [10, 20, 30, 40, 50];
+    /// let range = AnyCopyRange::from(1..=3);
+    /// assert_eq!(data[range.resolve(data.len()).unwrap()], data[range]);
+    /// ```
+    pub fn resolve(
+        &self,
+        len: usize,
+    ) -> Result<CopyRange<usize>, IndexRangeError> {
+        let range = match *self {
+            AnyCopyRange::Full => 0..len,
+            AnyCopyRange::From(range) => range.checked_to_len(len)?,
+            AnyCopyRange::To(range) => 0..range.end,
+            AnyCopyRange::ToInclusive(range) => {
+                CopyRangeInclusive { start: 0, end: range.end }
+                    .checked_exclusive()?
+            }
+            AnyCopyRange::Range(range) => range.into_std(),
+            AnyCopyRange::Inclusive(range) => range.checked_exclusive()?,
+        };
+        check_range(range, len).map(CopyRange::from)
+    }
+}
+
+impl<Idx: fmt::Debug> fmt::Debug for AnyCopyRange<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyCopyRange::Full => write!(fmt, ".."),
+            AnyCopyRange::From(range) => range.fmt(fmt),
+            AnyCopyRange::To(range) => range.fmt(fmt),
+            AnyCopyRange::ToInclusive(range) => range.fmt(fmt),
+            AnyCopyRange::Range(range) => range.fmt(fmt),
+            AnyCopyRange::Inclusive(range) => range.fmt(fmt),
+        }
+    }
+}
+
+impl<Idx: fmt::Display> fmt::Display for AnyCopyRange<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyCopyRange::Full => write!(fmt, ".."),
+            AnyCopyRange::From(range) => fmt::Display::fmt(range, fmt),
+            AnyCopyRange::To(range) => fmt::Display::fmt(range, fmt),
+            AnyCopyRange::ToInclusive(range) => fmt::Display::fmt(range, fmt),
+            AnyCopyRange::Range(range) => fmt::Display::fmt(range, fmt),
+            AnyCopyRange::Inclusive(range) => fmt::Display::fmt(range, fmt),
+        }
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for AnyCopyRange<Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        match self {
+            AnyCopyRange::From(CopyRangeFrom { start })
+            | AnyCopyRange::Range(CopyRange { start, .. })
+            | AnyCopyRange::Inclusive(CopyRangeInclusive { start, .. }) => {
+                Bound::Included(start)
+            }
+            AnyCopyRange::Full
+            | AnyCopyRange::To(_)
+            | AnyCopyRange::ToInclusive(_) => Bound::Unbounded,
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        match self {
+            AnyCopyRange::To(CopyRangeTo { end })
+            | AnyCopyRange::Range(CopyRange { end, .. }) => {
+                Bound::Excluded(end)
+            }
+            AnyCopyRange::ToInclusive(CopyRangeToInclusive { end })
+            | AnyCopyRange::Inclusive(CopyRangeInclusive { end, .. }) => {
+                Bound::Included(end)
+            }
+            AnyCopyRange::Full | AnyCopyRange::From(_) => Bound::Unbounded,
+        }
+    }
+}
+
+impl<Idx: ?Sized> RangeBounds<Idx> for AnyCopyRange<&Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        match *self {
+            AnyCopyRange::From(CopyRangeFrom { start })
+            | AnyCopyRange::Range(CopyRange { start, .. })
+            | AnyCopyRange::Inclusive(CopyRangeInclusive { start, .. }) => {
+                Bound::Included(start)
+            }
+            AnyCopyRange::Full
+            | AnyCopyRange::To(_)
+            | AnyCopyRange::ToInclusive(_) => Bound::Unbounded,
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        match *self {
+            AnyCopyRange::To(CopyRangeTo { end })
+            | AnyCopyRange::Range(CopyRange { end, .. }) => {
+                Bound::Excluded(end)
+            }
+            AnyCopyRange::ToInclusive(CopyRangeToInclusive { end })
+            | AnyCopyRange::Inclusive(CopyRangeInclusive { end, .. }) => {
+                Bound::Included(end)
+            }
+            AnyCopyRange::Full | AnyCopyRange::From(_) => Bound::Unbounded,
+        }
+    }
+}
+
+impl<Idx> From<CopyRangeFull> for AnyCopyRange<Idx> {
+    fn from(_: CopyRangeFull) -> Self {
+        AnyCopyRange::Full
+    }
+}
+
+impl<Idx> From<CopyRangeFrom<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: CopyRangeFrom<Idx>) -> Self {
+        AnyCopyRange::From(range)
+    }
+}
+
+impl<Idx> From<CopyRangeTo<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: CopyRangeTo<Idx>) -> Self {
+        AnyCopyRange::To(range)
+    }
+}
+
+impl<Idx> From<CopyRangeToInclusive<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: CopyRangeToInclusive<Idx>) -> Self {
+        AnyCopyRange::ToInclusive(range)
+    }
+}
+
+impl<Idx> From<CopyRange<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: CopyRange<Idx>) -> Self {
+        AnyCopyRange::Range(range)
+    }
+}
+
+impl<Idx> From<CopyRangeInclusive<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: CopyRangeInclusive<Idx>) -> Self {
+        AnyCopyRange::Inclusive(range)
+    }
+}
+
+impl<Idx> From<RangeFrom<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: RangeFrom<Idx>) -> Self {
+        AnyCopyRange::From(range.into())
+    }
+}
+
+impl<Idx> From<RangeTo<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: RangeTo<Idx>) -> Self {
+        AnyCopyRange::To(range.into())
+    }
+}
+
+impl<Idx> From<RangeToInclusive<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: RangeToInclusive<Idx>) -> Self {
+        AnyCopyRange::ToInclusive(range.into())
+    }
+}
+
+impl<Idx> From<Range<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: Range<Idx>) -> Self {
+        AnyCopyRange::Range(range.into())
+    }
+}
+
+/// Convert a [`RangeInclusive`] into an `AnyCopyRange`.
+///
+/// See the [note](CopyRangeInclusive::from_std) on converting an exhausted
+/// `RangeInclusive`.
+impl<Idx> From<RangeInclusive<Idx>> for AnyCopyRange<Idx> {
+    fn from(range: RangeInclusive<Idx>) -> Self {
+        AnyCopyRange::Inclusive(range.into())
+    }
+}
+
+/// Convert an `AnyCopyRange` into a [`RangeFull`][CopyRangeFull], if it is
+/// [`Full`](AnyCopyRange::Full), or return it unchanged otherwise.
+impl<Idx> TryFrom<AnyCopyRange<Idx>> for CopyRangeFull {
+    type Error = AnyCopyRange<Idx>;
+
+    fn try_from(range: AnyCopyRange<Idx>) -> Result<Self, AnyCopyRange<Idx>> {
+        match range {
+            AnyCopyRange::Full => Ok(..),
+            range => Err(range),
+        }
+    }
+}
+
+/// Implements `TryFrom<AnyCopyRange>` for the range types in this crate and
+/// `core::ops` which correspond to each variant.
+macro_rules! impl_try_from_any {
+    ($($variant:ident => $copy:ident, $std:ident;)*) => {$(
+        impl<Idx> TryFrom<AnyCopyRange<Idx>> for $copy<Idx> {
+            type Error = AnyCopyRange<Idx>;
+
+            fn try_from(range: AnyCopyRange<Idx>) -> Result<Self, AnyCopyRange<Idx>> {
+                match range {
+                    AnyCopyRange::$variant(range) => Ok(range),
+                    range => Err(range),
+                }
+            }
+        }
+
+        impl<Idx> TryFrom<AnyCopyRange<Idx>> for $std<Idx> {
+            type Error = AnyCopyRange<Idx>;
+
+            fn try_from(range: AnyCopyRange<Idx>) -> Result<Self, AnyCopyRange<Idx>> {
+                $copy::try_from(range).map(Into::into)
+            }
+        }
+    )*};
+}
+
+impl_try_from_any! {
+    From => CopyRangeFrom, RangeFrom;
+    To => CopyRangeTo, RangeTo;
+    ToInclusive => CopyRangeToInclusive, RangeToInclusive;
+    Range => CopyRange, Range;
+    Inclusive => CopyRangeInclusive, RangeInclusive;
+}
@@ -0,0 +1,161 @@
+//! Helper functions for working with [`Bound`]s.
+//!
+//! The `flip_*` functions convert between included and excluded bounds using
+//! [`CopyStep`], and are what the `TryFrom<(Bound<Idx>, Bound<Idx>)>` impls
+//! for the range types in this crate are built on. Each only changes bounds
+//! of one kind on one side, and passes other bounds (including `Unbounded`)
+//! through unchanged.
+
+use core::ops::Bound;
+
+use crate::CopyStep;
+
+/// Applies `f` to the value of `bound`, if any.
+///
+/// This is the same as `Bound::map`.
+///
+/// ```rust
+/// # use copy_range::bound;
+/// use std::ops::Bound::{Excluded, Unbounded};
+/// assert_eq!(bound::map(Excluded(2), |x| x * 10), Excluded(20));
+/// assert_eq!(bound::map(Unbounded::<i32>, |x| x * 10), Unbounded);
+/// ```
+pub fn map<T, U, F>(bound: Bound<T>, f: F) -> Bound<U>
+where
+    F: FnOnce(T) -> U,
+{
+    match bound {
+        Bound::Included(x) => Bound::Included(f(x)),
+        Bound::Excluded(x) => Bound::Excluded(f(x)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Copies the value of `bound`, if any.
+///
+/// ```rust
+/// # use copy_range::bound;
+/// use std::ops::Bound::{Included, Unbounded};
+/// assert_eq!(bound::copied(Included(&3)), Included(3));
+/// assert_eq!(bound::copied(Unbounded::<&i32>), Unbounded);
+/// ```
+pub fn copied<T: Copy>(bound: Bound<&T>) -> Bound<T> {
+    map(bound, |&x| x)
+}
+
+/// Clones the value of `bound`, if any.
+///
+/// ```rust
+/// # use copy_range::bound;
+/// use std::ops::Bound::{Excluded, Unbounded};
+/// let s = String::from("a");
+/// assert_eq!(bound::cloned(Excluded(&s)), Excluded(s.clone()));
+/// assert_eq!(bound::cloned(Unbounded::<&String>), Unbounded);
+/// ```
+pub fn cloned<T: Clone>(bound: Bound<&T>) -> Bound<T> {
+    map(bound, T::clone)
+}
+
+/// Borrows the value of `bound`, if any.
+///
+/// ```rust
+/// # use copy_range::bound;
+/// use std::ops::Bound::{Included, Unbounded};
+/// assert_eq!(bound::as_ref(&Included(3)), Included(&3));
+/// assert_eq!(bound::as_ref(&Unbounded::<i32>), Unbounded);
+/// ```
+pub fn as_ref<T>(bound: &Bound<T>) -> Bound<&T> {
+    match bound {
+        Bound::Included(x) => Bound::Included(x),
+        Bound::Excluded(x) => Bound::Excluded(x),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Converts an excluded start bound `Excluded(x)` into the equivalent
+/// included bound `Included(x + 1)`.
+///
+/// Returns `None` if `x` has no successor, e.g. if it is the maximum value of
+/// an integer type, since then nothing is after the bound.
+///
+/// ```rust
+/// # use copy_range::bound;
+/// use std::ops::Bound::{Excluded, Included, Unbounded};
+/// assert_eq!(bound::flip_excluded_start(Excluded(3u8)), Some(Included(4)));
+/// assert_eq!(bound::flip_excluded_start(Excluded(u8::MAX)), None);
+/// assert_eq!(bound::flip_excluded_start(Included(3u8)), Some(Included(3)));
+/// assert_eq!(bound::flip_excluded_start(Unbounded::<u8>), Some(Unbounded));
+/// ```
+pub fn flip_excluded_start<Idx: CopyStep>(
+    start: Bound<Idx>,
+) -> Option<Bound<Idx>> {
+    match start {
+        Bound::Excluded(x) => x.checked_successor().map(Bound::Included),
+        start => Some(start),
+    }
+}
+
+/// Converts an included start bound `Included(x)` into the equivalent
+/// excluded bound `Excluded(x - 1)`.
+///
+/// Returns `None` if `x` has no predecessor, e.g. if it is the minimum value
+/// of an integer type.
+///
+/// ```rust
+/// # use copy_range::bound;
+/// use std::ops::Bound::{Excluded, Included, Unbounded};
+/// assert_eq!(bound::flip_included_start(Included(3i8)), Some(Excluded(2)));
+/// assert_eq!(bound::flip_included_start(Included(i8::MIN)), None);
+/// assert_eq!(bound::flip_included_start(Excluded(3i8)), Some(Excluded(3)));
+/// assert_eq!(bound::flip_included_start(Unbounded::<i8>), Some(Unbounded));
+/// ```
+pub fn flip_included_start<Idx: CopyStep>(
+    start: Bound<Idx>,
+) -> Option<Bound<Idx>> {
+    match start {
+        Bound::Included(x) => x.checked_predecessor().map(Bound::Excluded),
+        start => Some(start),
+    }
+}
+
+/// Converts an included end bound `Included(x)` into the equivalent excluded
+/// bound `Excluded(x + 1)`.
+///
+/// Returns `None` if `x` has no successor, e.g. if it is the maximum value of
+/// an integer type.
+///
+/// ```rust
+/// # use copy_range::bound;
+/// use std::ops::Bound::{Excluded, Included, Unbounded};
+/// assert_eq!(bound::flip_included_end(Included(3u8)), Some(Excluded(4)));
+/// assert_eq!(bound::flip_included_end(Included(u8::MAX)), None);
+/// assert_eq!(bound::flip_included_end(Excluded(3u8)), Some(Excluded(3)));
+/// assert_eq!(bound::flip_included_end(Unbounded::<u8>), Some(Unbounded));
+/// ```
+pub fn flip_included_end<Idx: CopyStep>(end: Bound<Idx>) -> Option<Bound<Idx>> {
+    match end {
+        Bound::Included(x) => x.checked_successor().map(Bound::Excluded),
+        end => Some(end),
+    }
+}
+
+/// Converts an excluded end bound `Excluded(x)` into the equivalent included
+/// bound `Included(x - 1)`.
+///
+/// Returns `None` if `x` has no predecessor, e.g. if it is the minimum value
+/// of an integer type, since then nothing is before the bound.
+///
+/// ```rust
+/// # use copy_range::bound;
+/// use std::ops::Bound::{Excluded, Included, Unbounded};
+/// assert_eq!(bound::flip_excluded_end(Excluded(3i8)), Some(Included(2)));
+/// assert_eq!(bound::flip_excluded_end(Excluded(i8::MIN)), None);
+/// assert_eq!(bound::flip_excluded_end(Included(3i8)), Some(Included(3)));
+/// assert_eq!(bound::flip_excluded_end(Unbounded::<i8>), Some(Unbounded));
+/// ```
+pub fn flip_excluded_end<Idx: CopyStep>(end: Bound<Idx>) -> Option<Bound<Idx>> {
+    match end {
+        Bound::Excluded(x) => x.checked_predecessor().map(Bound::Included),
+        end => Some(end),
+    }
+}
@@ -0,0 +1,414 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{
+        Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive,
+        RangeTo, RangeToInclusive,
+    },
+};
+
+use crate::{
+    AnyCopyRange, CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo,
+    CopyRangeToInclusive, bound,
+};
+
+/// A pair of start and end [`Bound`]s.
+///
+/// This is like `(Bound<Idx>, Bound<Idx>)`, but it has named fields and
+/// methods, and can represent any range, including ranges with an excluded
+/// start bound, which none of the other range types in this crate can.
+///
+/// It can be converted from any of the range types in this crate or in
+/// `core::ops`, and implements [`RangeBounds`] with the same bounds as the
+/// range it was converted from, so it can be used to store ranges of any kind
+/// in a single `Copy` type:
+///
+/// ```rust
+/// # use copy_range::{AnyCopyRange, CopyBounds, CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeToInclusive};
+/// use std::collections::BTreeMap;
+/// use std::ops::Bound::{Excluded, Included, Unbounded};
+///
+/// let map: BTreeMap<u32, char> = (0..10).zip('a'..).collect();
+/// let values = |bounds: CopyBounds<u32>| -> String {
+///     map.range(bounds).map(|(_, &value)| value).collect()
+/// };
+///
+/// assert_eq!(values((2..5).into()), "cde");
+/// assert_eq!(values((2..=5).into()), "cdef");
+/// assert_eq!(values((7..).into()), "hij");
+/// assert_eq!(values((..3).into()), "abc");
+/// assert_eq!(values((..=3).into()), "abcd");
+/// assert_eq!(values((..).into()), "abcdefghij");
+/// assert_eq!(values(CopyRange { start: 2, end: 5 }.into()), "cde");
+/// assert_eq!(values(CopyRangeInclusive { start: 2, end: 5 }.into()), "cdef");
+/// assert_eq!(values(CopyRangeFrom { start: 7 }.into()), "hij");
+/// assert_eq!(values(CopyRangeToInclusive { end: 3 }.into()), "abcd");
+/// assert_eq!(values(AnyCopyRange::from(2..5).into()), "cde");
+/// assert_eq!(values((Excluded(2), Included(5)).into()), "def");
+/// assert_eq!(values((Excluded(7), Unbounded).into()), "ij");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CopyBounds<Idx> {
+    pub start: Bound<Idx>,
+    pub end: Bound<Idx>,
+}
+
+impl<Idx> CopyBounds<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// See [`RangeBounds::contains`].
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        <Self as RangeBounds<Idx>>::contains(self, item)
+    }
+
+    /// Returns `true` if the range contains no items.
+    ///
+    /// This only compares the bounds, so it treats `Idx` as if there may be
+    /// values between any two distinct values. For example, `(Excluded(3),
+    /// Excluded(4))` is not considered empty, even though no integer is
+    /// between 3 and 4.
+    ///
+    /// Note that `BTreeMap::range` panics for some empty ranges, e.g. if the
+    /// start is greater than the end, rather than yielding nothing.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyBounds;
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// let bounds = |start, end| CopyBounds { start, end };
+    /// assert!(!bounds(Included(3), Included(3)).is_empty());
+    /// assert!(bounds(Included(3), Excluded(3)).is_empty());
+    /// assert!(bounds(Excluded(3), Included(3)).is_empty());
+    /// assert!(!bounds(Excluded(3), Excluded(4)).is_empty());
+    /// assert!(!bounds(Unbounded, Excluded(i32::MIN)).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool
+    where
+        Idx: Ord,
+    {
+        match (&self.start, &self.end) {
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+            (Bound::Included(start), Bound::Included(end)) => start > end,
+            (Bound::Included(start), Bound::Excluded(end))
+            | (Bound::Excluded(start), Bound::Included(end))
+            | (Bound::Excluded(start), Bound::Excluded(end)) => start >= end,
+        }
+    }
+
+    /// Returns the range of elements which are in both `self` and `other`.
+    ///
+    /// The result may be empty (see [`is_empty`](Self::is_empty)), e.g. if
+    /// the ranges do not overlap.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyBounds;
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// let a = CopyBounds::from(2..=8);
+    /// let b = CopyBounds { start: Excluded(5), end: Unbounded };
+    /// assert_eq!(
+    ///     a.intersect(&b),
+    ///     CopyBounds { start: Excluded(5), end: Included(8) }
+    /// );
+    /// assert_eq!(
+    ///     b.intersect(&(..5).into()),
+    ///     CopyBounds { start: Excluded(5), end: Excluded(5) }
+    /// );
+    /// assert!(b.intersect(&(..5).into()).is_empty());
+    /// ```
+    pub fn intersect(&self, other: &CopyBounds<Idx>) -> CopyBounds<Idx>
+    where
+        Idx: Ord + Copy,
+    {
+        let start = match (self.start, other.start) {
+            (Bound::Unbounded, bound) | (bound, Bound::Unbounded) => bound,
+            (Bound::Included(a), Bound::Included(b)) => {
+                Bound::Included(a.max(b))
+            }
+            (Bound::Excluded(a), Bound::Excluded(b)) => {
+                Bound::Excluded(a.max(b))
+            }
+            (Bound::Included(included), Bound::Excluded(excluded))
+            | (Bound::Excluded(excluded), Bound::Included(included)) => {
+                match included.cmp(&excluded) {
+                    Ordering::Greater => Bound::Included(included),
+                    Ordering::Less | Ordering::Equal => {
+                        Bound::Excluded(excluded)
+                    }
+                }
+            }
+        };
+        let end = match (self.end, other.end) {
+            (Bound::Unbounded, bound) | (bound, Bound::Unbounded) => bound,
+            (Bound::Included(a), Bound::Included(b)) => {
+                Bound::Included(a.min(b))
+            }
+            (Bound::Excluded(a), Bound::Excluded(b)) => {
+                Bound::Excluded(a.min(b))
+            }
+            (Bound::Included(included), Bound::Excluded(excluded))
+            | (Bound::Excluded(excluded), Bound::Included(included)) => {
+                match included.cmp(&excluded) {
+                    Ordering::Less => Bound::Included(included),
+                    Ordering::Greater | Ordering::Equal => {
+                        Bound::Excluded(excluded)
+                    }
+                }
+            }
+        };
+        CopyBounds { start, end }
+    }
+
+    /// Borrows the bounds of the range.
+    ///
+    /// The returned range implements [`RangeBounds<Idx>`].
+    pub fn as_ref(&self) -> CopyBounds<&Idx> {
+        CopyBounds { start: self.start.as_ref(), end: self.end.as_ref() }
+    }
+}
+
+impl<Idx: fmt::Debug> fmt::Debug for CopyBounds<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Range syntax cannot express an excluded start bound.
+        if fmt.alternate() || matches!(self.start, Bound::Excluded(_)) {
+            return fmt
+                .debug_struct("CopyBounds")
+                .field("start", &self.start)
+                .field("end", &self.end)
+                .finish();
+        }
+        if let Bound::Included(start) = &self.start {
+            start.fmt(fmt)?;
+        }
+        match &self.end {
+            Bound::Included(end) => {
+                write!(fmt, "..=")?;
+                end.fmt(fmt)?;
+            }
+            Bound::Excluded(end) => {
+                write!(fmt, "..")?;
+                end.fmt(fmt)?;
+            }
+            Bound::Unbounded => write!(fmt, "..")?,
+        }
+        Ok(())
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for CopyBounds<Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        self.end.as_ref()
+    }
+}
+
+impl<Idx: ?Sized> RangeBounds<Idx> for CopyBounds<&Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        bound::copied(self.start.as_ref())
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        bound::copied(self.end.as_ref())
+    }
+}
+
+/// Convert a `(start, end)` pair of bounds into a `CopyBounds`.
+impl<Idx> From<(Bound<Idx>, Bound<Idx>)> for CopyBounds<Idx> {
+    fn from((start, end): (Bound<Idx>, Bound<Idx>)) -> Self {
+        CopyBounds { start, end }
+    }
+}
+
+/// Convert a `CopyBounds` into a `(start, end)` pair of bounds.
+impl<Idx> From<CopyBounds<Idx>> for (Bound<Idx>, Bound<Idx>) {
+    fn from(bounds: CopyBounds<Idx>) -> Self {
+        (bounds.start, bounds.end)
+    }
+}
+
+impl<Idx> From<RangeFull> for CopyBounds<Idx> {
+    fn from(_: RangeFull) -> Self {
+        CopyBounds { start: Bound::Unbounded, end: Bound::Unbounded }
+    }
+}
+
+impl<Idx> From<RangeFrom<Idx>> for CopyBounds<Idx> {
+    fn from(range: RangeFrom<Idx>) -> Self {
+        CopyBounds {
+            start: Bound::Included(range.start),
+            end: Bound::Unbounded,
+        }
+    }
+}
+
+impl<Idx> From<RangeTo<Idx>> for CopyBounds<Idx> {
+    fn from(range: RangeTo<Idx>) -> Self {
+        CopyBounds { start: Bound::Unbounded, end: Bound::Excluded(range.end) }
+    }
+}
+
+impl<Idx> From<RangeToInclusive<Idx>> for CopyBounds<Idx> {
+    fn from(range: RangeToInclusive<Idx>) -> Self {
+        CopyBounds { start: Bound::Unbounded, end: Bound::Included(range.end) }
+    }
+}
+
+impl<Idx> From<Range<Idx>> for CopyBounds<Idx> {
+    fn from(range: Range<Idx>) -> Self {
+        CopyBounds {
+            start: Bound::Included(range.start),
+            end: Bound::Excluded(range.end),
+        }
+    }
+}
+
+/// Convert a [`RangeInclusive`] into a `CopyBounds`.
+///
+/// Unlike converting it into a [`CopyRangeInclusive`], this is lossless: an
+/// exhausted `RangeInclusive` has an excluded end bound, and so does the
+/// returned `CopyBounds`.
+impl<Idx> From<RangeInclusive<Idx>> for CopyBounds<Idx> {
+    fn from(range: RangeInclusive<Idx>) -> Self {
+        let exhausted = matches!(range.end_bound(), Bound::Excluded(_));
+        let (start, end) = range.into_inner();
+        let end =
+            if exhausted { Bound::Excluded(end) } else { Bound::Included(end) };
+        CopyBounds { start: Bound::Included(start), end }
+    }
+}
+
+impl<Idx> From<CopyRangeFrom<Idx>> for CopyBounds<Idx> {
+    fn from(range: CopyRangeFrom<Idx>) -> Self {
+        CopyBounds {
+            start: Bound::Included(range.start),
+            end: Bound::Unbounded,
+        }
+    }
+}
+
+impl<Idx> From<CopyRangeTo<Idx>> for CopyBounds<Idx> {
+    fn from(range: CopyRangeTo<Idx>) -> Self {
+        CopyBounds { start: Bound::Unbounded, end: Bound::Excluded(range.end) }
+    }
+}
+
+impl<Idx> From<CopyRangeToInclusive<Idx>> for CopyBounds<Idx> {
+    fn from(range: CopyRangeToInclusive<Idx>) -> Self {
+        CopyBounds { start: Bound::Unbounded, end: Bound::Included(range.end) }
+    }
+}
+
+impl<Idx> From<CopyRange<Idx>> for CopyBounds<Idx> {
+    fn from(range: CopyRange<Idx>) -> Self {
+        CopyBounds {
+            start: Bound::Included(range.start),
+            end: Bound::Excluded(range.end),
+        }
+    }
+}
+
+impl<Idx> From<CopyRangeInclusive<Idx>> for CopyBounds<Idx> {
+    fn from(range: CopyRangeInclusive<Idx>) -> Self {
+        CopyBounds {
+            start: Bound::Included(range.start),
+            end: Bound::Included(range.end),
+        }
+    }
+}
+
+impl<Idx> From<AnyCopyRange<Idx>> for CopyBounds<Idx> {
+    fn from(range: AnyCopyRange<Idx>) -> Self {
+        match range {
+            AnyCopyRange::Full => (..).into(),
+            AnyCopyRange::From(range) => range.into(),
+            AnyCopyRange::To(range) => range.into(),
+            AnyCopyRange::ToInclusive(range) => range.into(),
+            AnyCopyRange::Range(range) => range.into(),
+            AnyCopyRange::Inclusive(range) => range.into(),
+        }
+    }
+}
+
+/// Converting a range into its start and end bounds by value.
+///
+/// This is like [`RangeBounds`], but returns owned bounds, so generic code
+/// does not need to clone them. It is implemented for all of the range types
+/// in this crate and in `core::ops`, and for `(Bound<Idx>, Bound<Idx>)`.
+///
+/// ```rust
+/// # use copy_range::{CopyIntoBounds, CopyRange, CopyRangeInclusive};
+/// use std::ops::Bound::{self, Excluded, Included, Unbounded};
+/// fn start<R: CopyIntoBounds<u32>>(range: R) -> Bound<u32> {
+///     range.into_bounds().0
+/// }
+/// assert_eq!(start(3..5), Included(3));
+/// assert_eq!(start(..5), Unbounded);
+/// assert_eq!(start(CopyRange { start: 3, end: 5 }), Included(3));
+/// assert_eq!(start(CopyRangeInclusive { start: 3, end: 5 }), Included(3));
+/// assert_eq!(start((Excluded(3), Unbounded)), Excluded(3));
+/// ```
+pub trait CopyIntoBounds<Idx> {
+    /// Returns the start and end bounds of the range.
+    fn into_bounds(self) -> (Bound<Idx>, Bound<Idx>);
+}
+
+/// Implements `CopyIntoBounds` via the type's `From` conversion into
+/// `CopyBounds`.
+macro_rules! impl_copy_into_bounds {
+    ($($ty:ty),* $(,)?) => {$(
+        impl<Idx> CopyIntoBounds<Idx> for $ty {
+            fn into_bounds(self) -> (Bound<Idx>, Bound<Idx>) {
+                CopyBounds::from(self).into()
+            }
+        }
+    )*};
+}
+
+impl_copy_into_bounds!(
+    RangeFull,
+    RangeFrom<Idx>,
+    RangeTo<Idx>,
+    RangeToInclusive<Idx>,
+    Range<Idx>,
+    RangeInclusive<Idx>,
+    CopyRangeFrom<Idx>,
+    CopyRangeTo<Idx>,
+    CopyRangeToInclusive<Idx>,
+    CopyRange<Idx>,
+    CopyRangeInclusive<Idx>,
+    AnyCopyRange<Idx>,
+    CopyBounds<Idx>,
+    (Bound<Idx>, Bound<Idx>),
+);
+
+/// Returns the range of elements which are in both `a` and `b`, which may be
+/// ranges of different kinds.
+///
+/// See [`CopyBounds::intersect`].
+///
+/// ```rust
+/// # use copy_range::{intersect_bounds, CopyBounds, CopyRangeFrom};
+/// use std::ops::Bound::{Excluded, Included};
+/// assert_eq!(
+///     intersect_bounds(2..=8, CopyRangeFrom { start: 5 }),
+///     CopyBounds::from(5..=8)
+/// );
+/// assert_eq!(
+///     intersect_bounds(..5, (Excluded(2), Included(9))),
+///     CopyBounds { start: Excluded(2), end: Excluded(5) }
+/// );
+/// assert_eq!(intersect_bounds(.., 1..2), CopyBounds::from(1..2));
+/// ```
+pub fn intersect_bounds<Idx, A, B>(a: A, b: B) -> CopyBounds<Idx>
+where
+    Idx: Ord + Copy,
+    A: CopyIntoBounds<Idx>,
+    B: CopyIntoBounds<Idx>,
+{
+    CopyBounds::from(a.into_bounds()).intersect(&b.into_bounds().into())
+}
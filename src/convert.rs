@@ -0,0 +1,328 @@
+use core::{num::TryFromIntError, ops::Bound};
+
+use crate::{
+    CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo,
+    CopyRangeToInclusive, CopyStep, TryFromBoundsError, bound,
+};
+
+/// Implements `From` between ranges of integer types for which the element
+/// types implement `From`.
+///
+/// This cannot be a blanket impl over `To: From<From>`, since that would
+/// overlap with the reflexive `impl<T> From<T> for T`.
+macro_rules! impl_from_int {
+    ($($from:ident => [$($to:ident)*],)*) => {$($(
+        impl From<CopyRange<$from>> for CopyRange<$to> {
+            fn from(range: CopyRange<$from>) -> Self {
+                range.map($to::from)
+            }
+        }
+
+        impl From<CopyRangeFrom<$from>> for CopyRangeFrom<$to> {
+            fn from(range: CopyRangeFrom<$from>) -> Self {
+                CopyRangeFrom { start: range.start.into() }
+            }
+        }
+
+        impl From<CopyRangeInclusive<$from>> for CopyRangeInclusive<$to> {
+            fn from(range: CopyRangeInclusive<$from>) -> Self {
+                range.map($to::from)
+            }
+        }
+
+        impl From<CopyRangeTo<$from>> for CopyRangeTo<$to> {
+            fn from(range: CopyRangeTo<$from>) -> Self {
+                CopyRangeTo { end: range.end.into() }
+            }
+        }
+
+        impl From<CopyRangeToInclusive<$from>> for CopyRangeToInclusive<$to> {
+            fn from(range: CopyRangeToInclusive<$from>) -> Self {
+                CopyRangeToInclusive { end: range.end.into() }
+            }
+        }
+    )*)*};
+}
+
+impl_from_int! {
+    u8 => [u16 u32 u64 u128 usize i16 i32 i64 i128 isize],
+    u16 => [u32 u64 u128 usize i32 i64 i128],
+    u32 => [u64 u128 i64 i128],
+    u64 => [u128 i128],
+    i8 => [i16 i32 i64 i128 isize],
+    i16 => [i32 i64 i128 isize],
+    i32 => [i64 i128],
+    i64 => [i128],
+}
+
+/// Implements `TryFrom` between ranges of integer types for which the element
+/// types implement `TryFrom` but not `From`.
+///
+/// Where the element types implement `From`, the range types do too, so the
+/// blanket `TryFrom` impl from `core` applies instead.
+macro_rules! impl_try_from_int {
+    ($($from:ident => [$($to:ident)*],)*) => {$($(
+        impl TryFrom<CopyRange<$from>> for CopyRange<$to> {
+            type Error = TryFromIntError;
+
+            fn try_from(range: CopyRange<$from>) -> Result<Self, TryFromIntError> {
+                range.try_map($to::try_from)
+            }
+        }
+
+        impl TryFrom<CopyRangeFrom<$from>> for CopyRangeFrom<$to> {
+            type Error = TryFromIntError;
+
+            fn try_from(range: CopyRangeFrom<$from>) -> Result<Self, TryFromIntError> {
+                Ok(CopyRangeFrom { start: range.start.try_into()? })
+            }
+        }
+
+        impl TryFrom<CopyRangeInclusive<$from>> for CopyRangeInclusive<$to> {
+            type Error = TryFromIntError;
+
+            fn try_from(
+                range: CopyRangeInclusive<$from>,
+            ) -> Result<Self, TryFromIntError> {
+                range.try_map($to::try_from)
+            }
+        }
+
+        impl TryFrom<CopyRangeTo<$from>> for CopyRangeTo<$to> {
+            type Error = TryFromIntError;
+
+            fn try_from(range: CopyRangeTo<$from>) -> Result<Self, TryFromIntError> {
+                Ok(CopyRangeTo { end: range.end.try_into()? })
+            }
+        }
+
+        impl TryFrom<CopyRangeToInclusive<$from>> for CopyRangeToInclusive<$to> {
+            type Error = TryFromIntError;
+
+            fn try_from(
+                range: CopyRangeToInclusive<$from>,
+            ) -> Result<Self, TryFromIntError> {
+                Ok(CopyRangeToInclusive { end: range.end.try_into()? })
+            }
+        }
+    )*)*};
+}
+
+impl_try_from_int! {
+    u8 => [i8],
+    u16 => [u8 i8 i16 isize],
+    u32 => [u8 u16 usize i8 i16 i32 isize],
+    u64 => [u8 u16 u32 usize i8 i16 i32 i64 isize],
+    u128 => [u8 u16 u32 u64 usize i8 i16 i32 i64 i128 isize],
+    usize => [u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 isize],
+    i8 => [u8 u16 u32 u64 u128 usize],
+    i16 => [u8 u16 u32 u64 u128 usize i8],
+    i32 => [u8 u16 u32 u64 u128 usize i8 i16 isize],
+    i64 => [u8 u16 u32 u64 u128 usize i8 i16 i32 isize],
+    i128 => [u8 u16 u32 u64 u128 usize i8 i16 i32 i64 isize],
+    isize => [u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128],
+}
+
+/// Returns the inclusive start of a range with the start bound `start`.
+fn included_start<Idx: CopyStep>(
+    start: Bound<Idx>,
+) -> Result<Idx, TryFromBoundsError> {
+    match bound::flip_excluded_start(start) {
+        Some(Bound::Included(start)) => Ok(start),
+        Some(Bound::Unbounded) => Err(TryFromBoundsError::UnboundedStart),
+        None | Some(Bound::Excluded(_)) => {
+            Err(TryFromBoundsError::ExcludedStart)
+        }
+    }
+}
+
+/// Convert a pair of bounds into a `CopyRange`.
+///
+/// `Excluded(start)` and `Included(end)` are accepted by adjusting them with
+/// [`CopyStep`].
+impl<Idx: CopyStep> TryFrom<(Bound<Idx>, Bound<Idx>)> for CopyRange<Idx> {
+    type Error = TryFromBoundsError;
+
+    fn try_from(
+        (start, end): (Bound<Idx>, Bound<Idx>),
+    ) -> Result<Self, TryFromBoundsError> {
+        let start = included_start(start)?;
+        let end = match bound::flip_included_end(end) {
+            Some(Bound::Excluded(end)) => end,
+            Some(Bound::Unbounded) => {
+                return Err(TryFromBoundsError::UnboundedEnd);
+            }
+            None | Some(Bound::Included(_)) => {
+                return Err(TryFromBoundsError::IncludedEnd);
+            }
+        };
+        Ok(CopyRange { start, end })
+    }
+}
+
+/// Convert a pair of bounds into a `CopyRangeFrom`.
+///
+/// `Excluded(start)` is accepted by adjusting it with [`CopyStep`].
+impl<Idx: CopyStep> TryFrom<(Bound<Idx>, Bound<Idx>)> for CopyRangeFrom<Idx> {
+    type Error = TryFromBoundsError;
+
+    fn try_from(
+        (start, end): (Bound<Idx>, Bound<Idx>),
+    ) -> Result<Self, TryFromBoundsError> {
+        let start = included_start(start)?;
+        match end {
+            Bound::Unbounded => Ok(CopyRangeFrom { start }),
+            Bound::Included(_) | Bound::Excluded(_) => {
+                Err(TryFromBoundsError::BoundedEnd)
+            }
+        }
+    }
+}
+
+/// Convert a pair of bounds into a `CopyRangeInclusive`.
+///
+/// `Excluded(start)` and `Excluded(end)` are accepted by adjusting them with
+/// [`CopyStep`].
+impl<Idx: CopyStep> TryFrom<(Bound<Idx>, Bound<Idx>)>
+    for CopyRangeInclusive<Idx>
+{
+    type Error = TryFromBoundsError;
+
+    fn try_from(
+        (start, end): (Bound<Idx>, Bound<Idx>),
+    ) -> Result<Self, TryFromBoundsError> {
+        let start = included_start(start)?;
+        let end = match bound::flip_excluded_end(end) {
+            Some(Bound::Included(end)) => end,
+            Some(Bound::Unbounded) => {
+                return Err(TryFromBoundsError::UnboundedEnd);
+            }
+            None | Some(Bound::Excluded(_)) => {
+                return Err(TryFromBoundsError::ExcludedEnd);
+            }
+        };
+        Ok(CopyRangeInclusive { start, end })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo,
+        CopyRangeToInclusive,
+    };
+
+    #[test]
+    fn from_widens_each_endpoint() {
+        let range = CopyRange { start: 1u8, end: 200 };
+        let wide = CopyRange::<u64>::from(CopyRange::<u32>::from(range));
+        assert_eq!(wide, range.map(u64::from));
+        let index: CopyRange<usize> = CopyRange { start: 3u16, end: 7 }.into();
+        assert_eq!(index, CopyRange { start: 3, end: 7 });
+
+        let range = CopyRangeInclusive { start: 0u8, end: u8::MAX };
+        let wide = CopyRangeInclusive::<u64>::from(
+            CopyRangeInclusive::<u32>::from(range),
+        );
+        assert_eq!(wide, CopyRangeInclusive { start: 0, end: 255 });
+
+        let range = CopyRangeFrom { start: u8::MAX };
+        let wide =
+            CopyRangeFrom::<u64>::from(CopyRangeFrom::<u32>::from(range));
+        assert_eq!(wide, CopyRangeFrom { start: 255 });
+
+        let range = CopyRangeTo { end: 7u8 };
+        let wide = CopyRangeTo::<u64>::from(CopyRangeTo::<u32>::from(range));
+        assert_eq!(wide, CopyRangeTo { end: 7 });
+
+        let range = CopyRangeToInclusive { end: 7u8 };
+        let wide = CopyRangeToInclusive::<u64>::from(
+            CopyRangeToInclusive::<u32>::from(range),
+        );
+        assert_eq!(wide, CopyRangeToInclusive { end: 7 });
+    }
+
+    #[test]
+    fn from_preserves_negative_endpoints() {
+        let range = CopyRange { start: i8::MIN, end: -1 };
+        let wide = CopyRange::<i64>::from(CopyRange::<i32>::from(range));
+        assert_eq!(wide, CopyRange { start: -128, end: -1 });
+
+        let range = CopyRangeInclusive { start: i8::MIN, end: i8::MAX };
+        let wide = CopyRangeInclusive::<isize>::from(range);
+        assert_eq!(wide, CopyRangeInclusive { start: -128, end: 127 });
+    }
+
+    #[test]
+    fn try_from_checks_each_endpoint() {
+        let range = CopyRange { start: 0u64, end: u64::from(u32::MAX) };
+        assert_eq!(
+            CopyRange::<u32>::try_from(range),
+            Ok(CopyRange { start: 0, end: u32::MAX }),
+        );
+        let range = CopyRange { start: 0u64, end: u64::from(u32::MAX) + 1 };
+        assert!(CopyRange::<u32>::try_from(range).is_err());
+        let range = CopyRange { start: u64::from(u32::MAX) + 1, end: 0 };
+        assert!(CopyRange::<u32>::try_from(range).is_err());
+        let range = CopyRange { start: -1i32, end: 5 };
+        assert!(CopyRange::<usize>::try_from(range).is_err());
+        let range = CopyRange { start: 0i32, end: 5 };
+        assert_eq!(
+            CopyRange::<usize>::try_from(range),
+            Ok(CopyRange { start: 0, end: 5 }),
+        );
+    }
+
+    #[test]
+    fn try_into_generic_api() {
+        fn read_at<R: TryInto<CopyRange<usize>>>(
+            data: &[u8],
+            range: R,
+        ) -> Option<&[u8]> {
+            range.try_into().ok()?.index_checked(data).ok()
+        }
+
+        let data = [1, 2, 3, 4];
+        assert_eq!(
+            read_at(&data, CopyRange { start: 1u64, end: 3 }),
+            Some(&[2, 3][..])
+        );
+        assert_eq!(
+            read_at(&data, CopyRange { start: 1u64, end: u64::MAX }),
+            None
+        );
+    }
+
+    #[test]
+    fn try_from_other_range_types_at_the_boundary() {
+        let range = CopyRangeInclusive { start: -1i32, end: 255 };
+        assert!(CopyRangeInclusive::<u8>::try_from(range).is_err());
+        let range = CopyRangeInclusive { start: 0i32, end: 256 };
+        assert!(CopyRangeInclusive::<u8>::try_from(range).is_err());
+        let range = CopyRangeInclusive { start: 0i32, end: 255 };
+        assert_eq!(
+            CopyRangeInclusive::<u8>::try_from(range),
+            Ok(CopyRangeInclusive { start: 0, end: 255 }),
+        );
+
+        let from =
+            |start| CopyRangeFrom::<u16>::try_from(CopyRangeFrom { start });
+        assert_eq!(
+            from(u32::from(u16::MAX)),
+            Ok(CopyRangeFrom { start: u16::MAX })
+        );
+        assert!(from(u32::from(u16::MAX) + 1).is_err());
+
+        let to = |end| CopyRangeTo::<u32>::try_from(CopyRangeTo { end });
+        assert_eq!(to(u64::from(u32::MAX)), Ok(CopyRangeTo { end: u32::MAX }));
+        assert!(to(u64::from(u32::MAX) + 1).is_err());
+
+        let to = |end| {
+            CopyRangeToInclusive::<i8>::try_from(CopyRangeToInclusive { end })
+        };
+        assert_eq!(to(-128i64), Ok(CopyRangeToInclusive { end: -128 }));
+        assert!(to(-129).is_err());
+        assert_eq!(to(127), Ok(CopyRangeToInclusive { end: 127 }));
+        assert!(to(128).is_err());
+    }
+}
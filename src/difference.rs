@@ -0,0 +1,80 @@
+use core::{iter::Chain, option};
+
+use crate::{CopyRange, CopyRangeInclusive};
+
+/// The elements of one [`CopyRange`] which are not contained in another.
+///
+/// This is returned by [`CopyRange::difference`]. It consists of up to two
+/// non-empty ranges, and iterating over it yields the ones which are present
+/// in increasing order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Difference<Idx> {
+    /// The elements before the removed range, if any.
+    pub before: Option<CopyRange<Idx>>,
+    /// The elements after the removed range, if any.
+    pub after: Option<CopyRange<Idx>>,
+}
+
+impl<Idx> IntoIterator for Difference<Idx> {
+    type Item = CopyRange<Idx>;
+
+    type IntoIter = Chain<
+        option::IntoIter<CopyRange<Idx>>,
+        option::IntoIter<CopyRange<Idx>>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.before.into_iter().chain(self.after)
+    }
+}
+
+/// The elements of one [`CopyRangeInclusive`] which are not contained in
+/// another.
+///
+/// This is returned by `difference` on integer `CopyRangeInclusive`s. It
+/// consists of up to two non-empty ranges, and iterating over it yields the
+/// ones which are present in increasing order.
+///
+/// ```rust
+/// # use copy_range::CopyRangeInclusive;
+/// // Every `u8` range with endpoints near either end of the type, including
+/// // reversed ranges, checked against the elements each one contains.
+/// let points = (0..=4).chain(251..=u8::MAX);
+/// let ranges = points.clone().flat_map(|start| {
+///     points.clone().map(move |end| CopyRangeInclusive { start, end })
+/// });
+/// for a in ranges.clone() {
+///     for b in ranges.clone() {
+///         let diff = a.difference(&b);
+///         for x in 0..=u8::MAX {
+///             let expected = a.contains(&x) && !b.contains(&x);
+///             let found = diff.into_iter().any(|piece| piece.contains(&x));
+///             assert_eq!(found, expected, "{a:?} - {b:?} at {x}");
+///         }
+///         assert!(diff.into_iter().all(|piece| !piece.is_empty()));
+///         if let (Some(before), Some(after)) = (diff.before, diff.after) {
+///             assert!(before.end < after.start);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InclusiveDifference<Idx> {
+    /// The elements before the removed range, if any.
+    pub before: Option<CopyRangeInclusive<Idx>>,
+    /// The elements after the removed range, if any.
+    pub after: Option<CopyRangeInclusive<Idx>>,
+}
+
+impl<Idx> IntoIterator for InclusiveDifference<Idx> {
+    type Item = CopyRangeInclusive<Idx>;
+
+    type IntoIter = Chain<
+        option::IntoIter<CopyRangeInclusive<Idx>>,
+        option::IntoIter<CopyRangeInclusive<Idx>>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.before.into_iter().chain(self.after)
+    }
+}
@@ -0,0 +1,264 @@
+use core::fmt;
+
+/// An error returned when a range of `usize` is not valid for indexing a
+/// particular slice or string slice.
+///
+/// All indices in this error are exclusive, like the end of a
+/// [`CopyRange`][crate::CopyRange], so e.g. the end of a
+/// [`CopyRangeInclusive`][crate::CopyRangeInclusive] is reported as one more
+/// than its `end` field.
+///
+/// Like the crate's other error types, it implements [`core::error::Error`],
+/// so it can be propagated into a boxed error:
+///
+/// ```rust
+/// # use copy_range::{CopyRange, IndexRangeError};
+/// use std::error::Error;
+/// fn first_word(
+///     text: &str,
+///     range: CopyRange<usize>,
+/// ) -> Result<&str, Box<dyn Error>> {
+///     let text = range.index_checked(text)?;
+///     Ok(text.split(' ').next().unwrap_or(text))
+/// }
+/// assert_eq!(
+///     first_word("hello world", CopyRange { start: 6, end: 11 }).unwrap(),
+///     "world"
+/// );
+/// let err = first_word("hello", CopyRange { start: 2, end: 8 }).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "range end index 8 out of range for slice of length 5"
+/// );
+/// assert!(err.downcast_ref::<IndexRangeError>().is_some());
+/// assert!(err.source().is_none());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IndexRangeError {
+    /// The start of the range is greater than the length of the slice.
+    StartOutOfBounds { start: usize, len: usize },
+    /// The end of the range is greater than the length of the slice.
+    EndOutOfBounds { end: usize, len: usize },
+    /// The start of the range is greater than its end.
+    StartGreaterThanEnd { start: usize, end: usize },
+    /// The end of an inclusive range is `usize::MAX`, so the range cannot end
+    /// within any slice.
+    EndOverflow,
+    /// An endpoint of the range is not on a `char` boundary of a string slice.
+    NotCharBoundary { index: usize },
+}
+
+impl fmt::Display for IndexRangeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            IndexRangeError::StartOutOfBounds { start, len } => write!(
+                fmt,
+                "range start index {start} out of range for slice of length \
+                 {len}"
+            ),
+            IndexRangeError::EndOutOfBounds { end, len } => write!(
+                fmt,
+                "range end index {end} out of range for slice of length {len}"
+            ),
+            IndexRangeError::StartGreaterThanEnd { start, end } => {
+                write!(fmt, "slice index starts at {start} but ends at {end}")
+            }
+            IndexRangeError::EndOverflow => {
+                write!(fmt, "attempted to index slice up to maximum usize")
+            }
+            IndexRangeError::NotCharBoundary { index } => {
+                write!(fmt, "byte index {index} is not a char boundary")
+            }
+        }
+    }
+}
+
+impl core::error::Error for IndexRangeError {}
+
+/// An error returned when a pair of [`Bound`][core::ops::Bound]s cannot be
+/// represented by a particular range type.
+///
+/// Excluded and included bounds are converted into each other using
+/// [`CopyStep`][crate::CopyStep] where needed, so this is only returned for
+/// such bounds if the conversion overflows.
+///
+/// ```rust
+/// # use copy_range::{CopyRange, CopyRangeFrom, CopyRangeInclusive, TryFromBoundsError};
+/// use std::ops::Bound::{self, Excluded, Included, Unbounded};
+/// use TryFromBoundsError::*;
+///
+/// let bounds = |start: Bound<u8>, end: Bound<u8>| (start, end);
+/// let range = |start, end| CopyRange { start, end };
+/// let inclusive = |start, end| CopyRangeInclusive { start, end };
+/// let from = |start| CopyRangeFrom { start };
+///
+/// let cases = [
+///     (bounds(Included(2), Included(5)), Ok(range(2, 6)), Ok(inclusive(2, 5)), Err(BoundedEnd)),
+///     (bounds(Included(2), Excluded(5)), Ok(range(2, 5)), Ok(inclusive(2, 4)), Err(BoundedEnd)),
+///     (bounds(Included(2), Unbounded), Err(UnboundedEnd), Err(UnboundedEnd), Ok(from(2))),
+///     (bounds(Excluded(2), Included(5)), Ok(range(3, 6)), Ok(inclusive(3, 5)), Err(BoundedEnd)),
+///     (bounds(Excluded(2), Excluded(5)), Ok(range(3, 5)), Ok(inclusive(3, 4)), Err(BoundedEnd)),
+///     (bounds(Excluded(2), Unbounded), Err(UnboundedEnd), Err(UnboundedEnd), Ok(from(3))),
+///     (bounds(Unbounded, Included(5)), Err(UnboundedStart), Err(UnboundedStart), Err(UnboundedStart)),
+///     (bounds(Unbounded, Excluded(5)), Err(UnboundedStart), Err(UnboundedStart), Err(UnboundedStart)),
+///     (bounds(Unbounded, Unbounded), Err(UnboundedStart), Err(UnboundedStart), Err(UnboundedStart)),
+/// ];
+/// for (bounds, as_range, as_inclusive, as_from) in cases {
+///     assert_eq!(CopyRange::try_from(bounds), as_range);
+///     assert_eq!(CopyRangeInclusive::try_from(bounds), as_inclusive);
+///     assert_eq!(CopyRangeFrom::try_from(bounds), as_from);
+/// }
+///
+/// // Adjusting a bound can overflow.
+/// assert_eq!(CopyRange::<u8>::try_from(bounds(Included(0), Included(255))), Err(IncludedEnd));
+/// assert_eq!(
+///     CopyRangeInclusive::<u8>::try_from(bounds(Excluded(255), Included(255))),
+///     Err(ExcludedStart),
+/// );
+/// assert_eq!(
+///     CopyRangeInclusive::<u8>::try_from(bounds(Included(0), Excluded(0))),
+///     Err(ExcludedEnd),
+/// );
+///
+/// let err: Box<dyn std::error::Error> = Box::new(UnboundedStart);
+/// assert_eq!(err.to_string(), "unbounded start bound");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TryFromBoundsError {
+    /// The start bound is `Unbounded`, but the range type has a start.
+    UnboundedStart,
+    /// The end bound is `Unbounded`, but the range type has an end.
+    UnboundedEnd,
+    /// The end bound is not `Unbounded`, but the range type has no end.
+    BoundedEnd,
+    /// The start bound is `Excluded`, and its value has no successor.
+    ExcludedStart,
+    /// The end bound is `Excluded`, the range type's end is inclusive, and its
+    /// value has no predecessor.
+    ExcludedEnd,
+    /// The end bound is `Included`, the range type's end is exclusive, and its
+    /// value has no successor.
+    IncludedEnd,
+}
+
+impl fmt::Display for TryFromBoundsError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            TryFromBoundsError::UnboundedStart => "unbounded start bound",
+            TryFromBoundsError::UnboundedEnd => "unbounded end bound",
+            TryFromBoundsError::BoundedEnd => "unexpected end bound",
+            TryFromBoundsError::ExcludedStart => {
+                "excluded start bound has no successor"
+            }
+            TryFromBoundsError::ExcludedEnd => {
+                "excluded end bound has no predecessor"
+            }
+            TryFromBoundsError::IncludedEnd => {
+                "included end bound has no successor"
+            }
+        };
+        fmt.write_str(message)
+    }
+}
+
+impl core::error::Error for TryFromBoundsError {}
+
+/// An error returned when parsing a range from a string fails.
+///
+/// `E` is the error type returned when parsing an endpoint fails, i.e.
+/// `<Idx as FromStr>::Err`.
+///
+/// This is returned both by the range types which require a particular
+/// syntax, and by [`AnyCopyRange`][crate::AnyCopyRange], which accepts any
+/// range syntax and so only returns some of these errors.
+///
+/// ```rust
+/// # use copy_range::{CopyRange, ParseCopyRangeError};
+/// use ParseCopyRangeError::*;
+/// assert_eq!("2..5".parse(), Ok(CopyRange { start: 2, end: 5 }));
+/// assert_eq!("2 .. 5".parse(), Ok(CopyRange { start: 2, end: 5 }));
+/// assert_eq!("-5..-2".parse(), Ok(CopyRange { start: -5, end: -2 }));
+/// assert_eq!("-1.5..2.5".parse(), Ok(CopyRange { start: -1.5, end: 2.5 }));
+///
+/// assert_eq!("2-5".parse::<CopyRange<i32>>(), Err(MissingSeparator));
+/// assert_eq!("2..=5".parse::<CopyRange<i32>>(), Err(InclusiveSeparator));
+/// assert_eq!("..5".parse::<CopyRange<i32>>(), Err(EmptyStart));
+/// assert_eq!("2..".parse::<CopyRange<i32>>(), Err(EmptyEnd));
+/// assert!(matches!("x..5".parse::<CopyRange<i32>>(), Err(InvalidStart(_))));
+/// assert!(matches!("2..y".parse::<CopyRange<i32>>(), Err(InvalidEnd(_))));
+/// assert!(matches!(
+///     "300..400".parse::<CopyRange<u8>>(),
+///     Err(InvalidStart(_))
+/// ));
+///
+/// // Empty endpoints are errors even if `Idx` can parse an empty string.
+/// assert_eq!("..b".parse::<CopyRange<String>>(), Err(EmptyStart));
+/// assert_eq!(" a .. ".parse::<CopyRange<String>>(), Err(EmptyEnd));
+///
+/// // The endpoint's parse error is available as the source.
+/// use std::error::Error;
+/// let err = "2..y".parse::<CopyRange<i32>>().unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "invalid range end: invalid digit found in string"
+/// );
+/// assert!(err.source().unwrap().is::<std::num::ParseIntError>());
+///
+/// // This also works once the error has been boxed, e.g. by `?`.
+/// let err: Box<dyn Error + Send + Sync> = err.into();
+/// assert!(err.is::<ParseCopyRangeError<std::num::ParseIntError>>());
+/// assert!(err.source().unwrap().is::<std::num::ParseIntError>());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseCopyRangeError<E> {
+    /// The string does not contain `..`.
+    MissingSeparator,
+    /// The string contains `..=`, but the range type's end is exclusive.
+    InclusiveSeparator,
+    /// There is nothing but whitespace before the separator.
+    EmptyStart,
+    /// There is nothing but whitespace after the separator.
+    EmptyEnd,
+    /// The start could not be parsed.
+    InvalidStart(E),
+    /// The end could not be parsed.
+    InvalidEnd(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ParseCopyRangeError<E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCopyRangeError::MissingSeparator => {
+                fmt.write_str("missing `..` in range")
+            }
+            ParseCopyRangeError::InclusiveSeparator => {
+                fmt.write_str("unexpected `..=` in exclusive range")
+            }
+            ParseCopyRangeError::EmptyStart => {
+                fmt.write_str("empty range start")
+            }
+            ParseCopyRangeError::EmptyEnd => fmt.write_str("empty range end"),
+            ParseCopyRangeError::InvalidStart(err) => {
+                write!(fmt, "invalid range start: {err}")
+            }
+            ParseCopyRangeError::InvalidEnd(err) => {
+                write!(fmt, "invalid range end: {err}")
+            }
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error
+    for ParseCopyRangeError<E>
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseCopyRangeError::InvalidStart(err)
+            | ParseCopyRangeError::InvalidEnd(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use core::fmt;
+
+use crate::{
+    CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo,
+    CopyRangeToInclusive,
+};
+
+/// Implements the given formatting traits for the range types by formatting
+/// each endpoint with that trait, using the same separators as `Debug`.
+macro_rules! impl_fmt {
+    ($($trait:ident)*) => {$(
+        impl<Idx: fmt::$trait> fmt::$trait for CopyRange<Idx> {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$trait::fmt(&self.start, fmt)?;
+                write!(fmt, "..")?;
+                fmt::$trait::fmt(&self.end, fmt)?;
+                Ok(())
+            }
+        }
+
+        impl<Idx: fmt::$trait> fmt::$trait for CopyRangeFrom<Idx> {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$trait::fmt(&self.start, fmt)?;
+                write!(fmt, "..")?;
+                Ok(())
+            }
+        }
+
+        impl<Idx: fmt::$trait> fmt::$trait for CopyRangeInclusive<Idx> {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$trait::fmt(&self.start, fmt)?;
+                write!(fmt, "..=")?;
+                fmt::$trait::fmt(&self.end, fmt)?;
+                Ok(())
+            }
+        }
+
+        impl<Idx: fmt::$trait> fmt::$trait for CopyRangeTo<Idx> {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(fmt, "..")?;
+                fmt::$trait::fmt(&self.end, fmt)?;
+                Ok(())
+            }
+        }
+
+        impl<Idx: fmt::$trait> fmt::$trait for CopyRangeToInclusive<Idx> {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(fmt, "..=")?;
+                fmt::$trait::fmt(&self.end, fmt)?;
+                Ok(())
+            }
+        }
+    )*};
+}
+
+impl_fmt!(Display LowerHex UpperHex Binary Octal);
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::{format, string::ToString};
+
+    use crate::{
+        CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo,
+        CopyRangeToInclusive,
+    };
+
+    #[test]
+    fn hex() {
+        let range = CopyRange { start: 0x10, end: 0x40 };
+        assert_eq!(format!("{range:x}"), "10..40");
+        assert_eq!(format!("{range:#x}"), "0x10..0x40");
+        assert_eq!(format!("{range:X}"), "10..40");
+        assert_eq!(format!("{range:#X}"), "0x10..0x40");
+        // Width and padding apply to each endpoint.
+        assert_eq!(format!("{range:04x}"), "0010..0040");
+
+        assert_eq!(format!("{:x}", CopyRangeFrom { start: 0xab }), "ab..");
+        let range = CopyRangeInclusive { start: 0u8, end: 0xff };
+        assert_eq!(format!("{range:#x}"), "0x0..=0xff");
+        assert_eq!(format!("{range:X}"), "0..=FF");
+        assert_eq!(format!("{:X}", CopyRangeTo { end: 0xab }), "..AB");
+        assert_eq!(
+            format!("{:#x}", CopyRangeToInclusive { end: 0xab }),
+            "..=0xab"
+        );
+    }
+
+    #[test]
+    fn binary_and_octal() {
+        let range = CopyRange { start: 0x10, end: 0x40 };
+        assert_eq!(format!("{range:b}"), "10000..1000000");
+        assert_eq!(format!("{range:#b}"), "0b10000..0b1000000");
+        assert_eq!(format!("{range:o}"), "20..100");
+        assert_eq!(format!("{range:#o}"), "0o20..0o100");
+        assert_eq!(format!("{range:08b}"), "00010000..01000000");
+
+        assert_eq!(format!("{:#b}", CopyRangeFrom { start: 5 }), "0b101..");
+        let range = CopyRangeInclusive { start: 0u8, end: 0o17 };
+        assert_eq!(format!("{range:b}"), "0..=1111");
+        assert_eq!(format!("{range:#o}"), "0o0..=0o17");
+        assert_eq!(format!("{:o}", CopyRangeTo { end: 8 }), "..10");
+        assert_eq!(format!("{:b}", CopyRangeToInclusive { end: 8 }), "..=1000");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", CopyRange { start: 1, end: 5 }), "1..5");
+        assert_eq!(CopyRangeFrom { start: -1 }.to_string(), "-1..");
+        assert_eq!(
+            CopyRangeInclusive { start: 1, end: 5 }.to_string(),
+            "1..=5"
+        );
+        assert_eq!(CopyRangeTo { end: 5 }.to_string(), "..5");
+        assert_eq!(CopyRangeToInclusive { end: 5 }.to_string(), "..=5");
+    }
+
+    #[test]
+    fn display_does_not_quote_strings() {
+        let range = CopyRange { start: "a", end: "z" };
+        assert_eq!(range.to_string(), "a..z");
+        assert_eq!(format!("{range:?}"), r#""a".."z""#);
+        assert_eq!(CopyRangeFrom { start: "a" }.to_string(), "a..");
+        assert_eq!(
+            CopyRangeInclusive { start: "a", end: "z" }.to_string(),
+            "a..=z"
+        );
+        assert_eq!(CopyRangeTo { end: "z" }.to_string(), "..z");
+        assert_eq!(CopyRangeToInclusive { end: "z" }.to_string(), "..=z");
+    }
+}
@@ -0,0 +1,399 @@
+use core::{iter::FusedIterator, num::NonZeroU64};
+
+use crate::CopyRangeInclusive;
+
+/// An iterator over every `step`th element of a range of integers, starting
+/// with the first.
+///
+/// This is returned by `step_by` on integer [`CopyRange`][crate::CopyRange]s
+/// and [`CopyRangeInclusive`][crate::CopyRangeInclusive]s. Unlike
+/// [`core::iter::StepBy`], its type only depends on the element type.
+///
+/// It yields the same elements as the standard library's `step_by`, from
+/// either end, including for ranges which end at the maximum value of the
+/// integer type:
+///
+/// ```rust
+/// # use copy_range::{CopyRange, CopyRangeInclusive};
+/// macro_rules! check {
+///     ($ty:ty) => {
+///         for start in <$ty>::MIN..=<$ty>::MAX {
+///             for end in <$ty>::MIN..=<$ty>::MAX {
+///                 for step in [1, 2, 7, usize::MAX] {
+///                     let ours =
+///                         CopyRangeInclusive { start, end }.step_by(step);
+///                     let std = (start..=end).step_by(step);
+///                     assert!(ours.eq(std.clone()));
+///                     assert!(ours.rev().eq(std.rev()));
+///
+///                     let ours = CopyRange { start, end }.step_by(step);
+///                     let std = (start..end).step_by(step);
+///                     assert!(ours.eq(std.clone()));
+///                     assert!(ours.rev().eq(std.rev()));
+///                 }
+///             }
+///         }
+///     };
+/// }
+/// check!(u8);
+/// check!(i8);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CopyStepBy<Idx> {
+    /// The next element to be yielded from the front.
+    front: Idx,
+    /// The next element to be yielded from the back. This is always `front`
+    /// plus a multiple of `step`.
+    back: Idx,
+    step: usize,
+    exhausted: bool,
+}
+
+macro_rules! impl_step_by {
+    ($($ty:ident)*) => {$(
+        impl CopyStepBy<$ty> {
+            /// Creates an iterator over every `step`th element of
+            /// `start..=end`.
+            ///
+            /// Panics if `step` is 0.
+            pub(crate) fn new_inclusive(start: $ty, end: $ty, step: usize) -> Self {
+                assert!(step != 0, "step must be non-zero");
+                if start > end {
+                    return Self { front: start, back: end, step, exhausted: true };
+                }
+                let distance = end.abs_diff(start) as u128;
+                let offset = distance - distance % step as u128;
+                // `offset <= distance`, so this does not actually wrap.
+                let back = start.wrapping_add(offset as $ty);
+                Self { front: start, back, step, exhausted: false }
+            }
+        }
+
+        impl Iterator for CopyStepBy<$ty> {
+            type Item = $ty;
+
+            fn next(&mut self) -> Option<$ty> {
+                if self.exhausted {
+                    return None;
+                }
+                let item = self.front;
+                if self.front == self.back {
+                    self.exhausted = true;
+                } else {
+                    // `back - front` is a nonzero multiple of `step`, so this
+                    // does not actually wrap.
+                    self.front = self.front.wrapping_add(self.step as $ty);
+                }
+                Some(item)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.exhausted {
+                    return (0, Some(0));
+                }
+                let distance = self.back.abs_diff(self.front) as u128;
+                match (distance / self.step as u128)
+                    .checked_add(1)
+                    .and_then(|len| usize::try_from(len).ok())
+                {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for CopyStepBy<$ty> {
+            fn next_back(&mut self) -> Option<$ty> {
+                if self.exhausted {
+                    return None;
+                }
+                let item = self.back;
+                if self.front == self.back {
+                    self.exhausted = true;
+                } else {
+                    // `back - front` is a nonzero multiple of `step`, so this
+                    // does not actually wrap.
+                    self.back = self.back.wrapping_sub(self.step as $ty);
+                }
+                Some(item)
+            }
+        }
+
+        impl FusedIterator for CopyStepBy<$ty> {}
+    )*};
+}
+
+impl_step_by!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// An iterator over consecutive, non-overlapping subranges of a range of
+/// integers, each with at most a given number of elements.
+///
+/// This is returned by `chunks` on integer
+/// [`CopyRangeInclusive`][crate::CopyRangeInclusive]s. Every chunk except
+/// possibly the last has exactly the given number of elements.
+///
+/// It can be consumed from both ends, and the chunks are the same either way,
+/// so iterating in reverse yields the possibly-shorter last chunk first:
+///
+/// ```rust
+/// # use copy_range::CopyRangeInclusive;
+/// # use std::num::NonZeroU64;
+/// let size = NonZeroU64::new(100).unwrap();
+/// let mut chunks = CopyRangeInclusive { start: 0u8, end: 255 }.chunks(size);
+/// assert_eq!(
+///     chunks.next_back(),
+///     Some(CopyRangeInclusive { start: 200, end: 255 })
+/// );
+/// assert_eq!(chunks.next(), Some(CopyRangeInclusive { start: 0, end: 99 }));
+/// assert_eq!(
+///     chunks.next_back(),
+///     Some(CopyRangeInclusive { start: 100, end: 199 })
+/// );
+/// assert_eq!(chunks.next(), None);
+/// assert_eq!(chunks.next_back(), None);
+/// ```
+///
+/// Together, the chunks contain every element of the range exactly once, in
+/// order:
+///
+/// ```rust
+/// # use copy_range::CopyRangeInclusive;
+/// # use std::num::NonZeroU64;
+/// let size = NonZeroU64::new(7).unwrap();
+/// let range = CopyRangeInclusive { start: 0u8, end: u8::MAX };
+/// let chunks: Vec<_> = range.chunks(size).collect();
+/// // 256 = 36 * 7 + 4
+/// assert_eq!(chunks.len(), 37);
+/// assert!(chunks[..36].iter().all(|chunk| chunk.count() == 7));
+/// assert_eq!(chunks[36], CopyRangeInclusive { start: 252, end: 255 });
+/// assert!(chunks.iter().flat_map(|&chunk| chunk).eq(0..=u8::MAX));
+/// assert!(range.chunks(size).rev().eq(chunks.into_iter().rev()));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct InclusiveChunks<Idx> {
+    /// The start of the next chunk.
+    start: Idx,
+    end: Idx,
+    size: NonZeroU64,
+    /// Whether every chunk has been yielded. This is needed because `start`
+    /// cannot be advanced past `end` when `end` is the maximum value of
+    /// `Idx`.
+    exhausted: bool,
+}
+
+macro_rules! impl_inclusive_chunks {
+    ($($ty:ident)*) => {$(
+        impl InclusiveChunks<$ty> {
+            pub(crate) fn new(range: CopyRangeInclusive<$ty>, size: NonZeroU64) -> Self {
+                Self {
+                    start: range.start,
+                    end: range.end,
+                    size,
+                    exhausted: range.is_empty(),
+                }
+            }
+        }
+
+        impl Iterator for InclusiveChunks<$ty> {
+            type Item = CopyRangeInclusive<$ty>;
+
+            fn next(&mut self) -> Option<CopyRangeInclusive<$ty>> {
+                if self.exhausted {
+                    return None;
+                }
+                let distance = self.end.abs_diff(self.start) as u128;
+                let offset = u128::from(self.size.get() - 1);
+                if distance <= offset {
+                    self.exhausted = true;
+                    return Some(CopyRangeInclusive { start: self.start, end: self.end });
+                }
+                // `offset < distance`, so this does not actually wrap, and
+                // `chunk_end < end`, so `chunk_end + 1` does not overflow.
+                let chunk_end = self.start.wrapping_add(offset as $ty);
+                let chunk = CopyRangeInclusive { start: self.start, end: chunk_end };
+                self.start = chunk_end + 1;
+                Some(chunk)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.exhausted {
+                    return (0, Some(0));
+                }
+                let distance = self.end.abs_diff(self.start) as u128;
+                match (distance / u128::from(self.size.get()))
+                    .checked_add(1)
+                    .and_then(|len| usize::try_from(len).ok())
+                {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for InclusiveChunks<$ty> {
+            fn next_back(&mut self) -> Option<CopyRangeInclusive<$ty>> {
+                if self.exhausted {
+                    return None;
+                }
+                // Chunks are aligned to `start`, so the last chunk starts at
+                // the largest multiple of `size` past `start` which is not
+                // past `end`.
+                let distance = self.end.abs_diff(self.start) as u128;
+                let offset = distance - distance % u128::from(self.size.get());
+                // `offset <= distance`, so this does not actually wrap.
+                let chunk_start = self.start.wrapping_add(offset as $ty);
+                let chunk = CopyRangeInclusive { start: chunk_start, end: self.end };
+                if offset == 0 {
+                    self.exhausted = true;
+                } else {
+                    // `chunk_start > start`, so this does not overflow.
+                    self.end = chunk_start - 1;
+                }
+                Some(chunk)
+            }
+        }
+
+        impl FusedIterator for InclusiveChunks<$ty> {}
+    )*};
+}
+
+impl_inclusive_chunks!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// An iterator over every element of a range of integers bounded only below,
+/// which stops after the maximum value of the integer type instead of
+/// overflowing.
+///
+/// This is returned by `iter_checked` on integer
+/// [`CopyRangeFrom`][crate::CopyRangeFrom]s.
+///
+/// ```rust
+/// # use copy_range::CopyRangeFrom;
+/// let items: Vec<u8> =
+///     CopyRangeFrom { start: 250u8 }.iter_checked().collect();
+/// assert_eq!(items, [250, 251, 252, 253, 254, 255]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CheckedFromIter<Idx> {
+    /// The next element to be yielded, or `None` if every element has been
+    /// yielded.
+    next: Option<Idx>,
+}
+
+macro_rules! impl_checked_from_iter {
+    ($($ty:ident)*) => {$(
+        impl CheckedFromIter<$ty> {
+            pub(crate) fn new(start: $ty) -> Self {
+                Self { next: Some(start) }
+            }
+        }
+
+        impl Iterator for CheckedFromIter<$ty> {
+            type Item = $ty;
+
+            fn next(&mut self) -> Option<$ty> {
+                let item = self.next?;
+                self.next = item.checked_add(1);
+                Some(item)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let Some(next) = self.next else {
+                    return (0, Some(0));
+                };
+                match ($ty::MAX.abs_diff(next) as u128)
+                    .checked_add(1)
+                    .and_then(|len| usize::try_from(len).ok())
+                {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl FusedIterator for CheckedFromIter<$ty> {}
+    )*};
+}
+
+impl_checked_from_iter!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// An iterator over every element of a range of integers, which assumes that
+/// the range is not reversed.
+///
+/// This is returned by `unchecked_iter` on integer
+/// [`CopyRange`][crate::CopyRange]s. It stops when `start == end`, rather than
+/// when `start >= end` like [`Range`][core::ops::Range]'s iterator.
+///
+/// If the range was reversed (i.e. `start > end`), this is still safe, but it
+/// will wrap around past the maximum value of the integer type, yielding every
+/// integer *not* in `end..start`.
+///
+/// It can be consumed from both ends:
+///
+/// ```rust
+/// # use copy_range::CopyRange;
+/// let mut iter = CopyRange { start: 250u8, end: 255 }.unchecked_iter();
+/// assert_eq!(iter.next_back(), Some(254));
+/// assert_eq!(iter.next(), Some(250));
+/// assert_eq!(iter.next_back(), Some(253));
+/// assert_eq!(iter.next(), Some(251));
+/// assert_eq!(iter.next_back(), Some(252));
+/// assert_eq!(iter.next(), None);
+/// assert_eq!(iter.next_back(), None);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UncheckedIter<Idx> {
+    start: Idx,
+    end: Idx,
+}
+
+macro_rules! impl_unchecked_iter {
+    ($($ty:ident)*) => {$(
+        impl UncheckedIter<$ty> {
+            pub(crate) fn new(start: $ty, end: $ty) -> Self {
+                Self { start, end }
+            }
+        }
+
+        impl Iterator for UncheckedIter<$ty> {
+            type Item = $ty;
+
+            fn next(&mut self) -> Option<$ty> {
+                if self.start == self.end {
+                    return None;
+                }
+                let item = self.start;
+                self.start = self.start.wrapping_add(1);
+                Some(item)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let distance = self.end.abs_diff(self.start);
+                let len = if self.start <= self.end {
+                    Some(distance as u128)
+                } else {
+                    // The iterator wraps around, so it yields every value
+                    // except the `distance` values in `end..start`.
+                    (($ty::MAX.abs_diff($ty::MIN) - distance) as u128).checked_add(1)
+                };
+                match len.and_then(|len| usize::try_from(len).ok()) {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for UncheckedIter<$ty> {
+            fn next_back(&mut self) -> Option<$ty> {
+                if self.start == self.end {
+                    return None;
+                }
+                self.end = self.end.wrapping_sub(1);
+                Some(self.end)
+            }
+        }
+
+        impl FusedIterator for UncheckedIter<$ty> {}
+    )*};
+}
+
+impl_unchecked_iter!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
@@ -7,15 +7,124 @@
 //! element type implements `Copy`, and they implement `IntoIterator` instead of
 //! `Iterator`.
 //!
+//! [`CopyRangeTo`] and [`CopyRangeToInclusive`] are additionally provided as
+//! counterparts to [`RangeTo`] and [`RangeToInclusive`], so that they can have
+//! the same inherent methods as the other range types.
+//!
 //! They are freely convertible to and from their `core::ops` counterparts (with
 //! a [note](CopyRangeInclusive::from_std) about `RangeInclusive`), and they
 //! implement most of the same (non-iterator-related) traits, notably
 //! [`RangeBounds`].
 //!
-//! Ranges of `usize` are additionally usable as the [`Index`] parameter for
-//! [arrays](prim@array), [slices](prim@slice), [string slices](prim@str) and
-//! (with the `"alloc"` feature enabled) [`Vec`][alloc::vec::Vec] and
-//! [`String`][alloc::string::String].
+//! Ranges of `usize` (including [`AnyCopyRange<usize>`]) are additionally
+//! usable as the [`Index`] parameter for [arrays](prim@array),
+//! [slices](prim@slice), [string slices](prim@str) and (with the `"alloc"`
+//! feature enabled) [`Vec`][alloc::vec::Vec] and
+//! [`String`][alloc::string::String]. With the `"smallvec"` feature enabled,
+//! [`SmallVec`](https://docs.rs/smallvec/1/smallvec/struct.SmallVec.html) can
+//! be indexed by them too, whether it is inline or spilled:
+//!
+//! ```rust
+//! # #[cfg(feature = "smallvec")] {
+//! # use copy_range::{CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo, CopyRangeToInclusive};
+//! use smallvec::{SmallVec, smallvec};
+//! let mut inline: SmallVec<[u8; 8]> = smallvec![1, 2, 3, 4, 5];
+//! let mut spilled: SmallVec<[u8; 2]> = smallvec![1, 2, 3, 4, 5];
+//! assert!(!inline.spilled() && spilled.spilled());
+//!
+//! assert_eq!(inline[CopyRange { start: 1, end: 3 }], [2, 3]);
+//! assert_eq!(spilled[CopyRange { start: 1, end: 3 }], [2, 3]);
+//! assert_eq!(spilled[CopyRangeFrom { start: 3 }], [4, 5]);
+//! assert_eq!(spilled[CopyRangeInclusive { start: 1, end: 3 }], [2, 3, 4]);
+//! assert_eq!(spilled[CopyRangeTo { end: 2 }], [1, 2]);
+//! assert_eq!(spilled[CopyRangeToInclusive { end: 0 }], [1]);
+//!
+//! inline[CopyRangeFrom { start: 3 }].fill(0);
+//! spilled[CopyRangeInclusive { start: 0, end: 1 }].fill(9);
+//! assert_eq!(inline[..], [1, 2, 3, 0, 0]);
+//! assert_eq!(spilled[..], [9, 9, 3, 4, 5]);
+//! # }
+//! ```
+//!
+//! Since they implement [`RangeBounds`], they can also be passed to APIs which
+//! take any `RangeBounds<usize>`, such as [`Bytes::slice`] from the `bytes`
+//! crate, and types which dereference to slices can be indexed by them:
+//!
+//! ```rust
+//! # use copy_range::{CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo, CopyRangeToInclusive};
+//! use bytes::{Bytes, BytesMut};
+//! let bytes = Bytes::from_static(b"hello world");
+//! assert_eq!(bytes.slice(CopyRange { start: 6, end: 11 }), "world");
+//! assert_eq!(bytes.slice(CopyRangeFrom { start: 6 }), "world");
+//! assert_eq!(bytes.slice(CopyRangeInclusive { start: 0, end: 4 }), "hello");
+//! assert_eq!(bytes.slice(CopyRangeTo { end: 5 }), "hello");
+//! assert_eq!(bytes.slice(CopyRangeToInclusive { end: 4 }), "hello");
+//!
+//! let mut buf = BytesMut::from(&b"hello world"[..]);
+//! buf[CopyRange { start: 0, end: 5 }].make_ascii_uppercase();
+//! assert_eq!(&buf[CopyRangeToInclusive { end: 6 }], b"HELLO w");
+//! ```
+//!
+//! This includes [`ArrayVec`] from the `arrayvec` crate, which only needs
+//! `core`, so it can be indexed by them without the `"alloc"` feature.
+//! Indexing is checked against its length, not its capacity:
+//!
+//! ```rust
+//! # use copy_range::{CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo, CopyRangeToInclusive};
+//! use arrayvec::ArrayVec;
+//! let mut vec: ArrayVec<u8, 8> = ArrayVec::new();
+//! vec.extend([1, 2, 3, 4, 5]);
+//! assert_eq!(vec[CopyRange { start: 1, end: 3 }], [2, 3]);
+//! assert_eq!(vec[CopyRangeFrom { start: 3 }], [4, 5]);
+//! assert_eq!(vec[CopyRangeInclusive { start: 1, end: 3 }], [2, 3, 4]);
+//! assert_eq!(vec[CopyRangeTo { end: 2 }], [1, 2]);
+//! assert_eq!(vec[CopyRangeToInclusive { end: 0 }], [1]);
+//!
+//! vec[CopyRangeFrom { start: 3 }].fill(0);
+//! assert_eq!(vec[..], [1, 2, 3, 0, 0]);
+//! assert!(CopyRange { start: 0, end: 6 }.index_checked(&vec[..]).is_err());
+//! ```
+//!
+//! [`Bytes::slice`]: https://docs.rs/bytes/1/bytes/struct.Bytes.html#method.slice
+//! [`ArrayVec`]: https://docs.rs/arrayvec/0.7/arrayvec/struct.ArrayVec.html
+//!
+//! With the `"ndarray"` feature enabled, ranges of `isize`, `usize` and `i32`
+//! (including [`AnyCopyRange`]) can be converted into `ndarray`'s [`Slice`]
+//! and [`SliceInfoElem`] types, with the same meaning as the corresponding
+//! `core::ops` ranges (i.e. with a step of 1, and negative endpoints counting
+//! from the end of the axis). This means they can be used for slicing with
+//! methods which take a `Slice`, or in the [`s!`] macro by wrapping them in
+//! `Slice::from`:
+//!
+//! ```rust
+//! # #[cfg(feature = "ndarray")] {
+//! # use copy_range::{CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo, CopyRangeToInclusive};
+//! use ndarray::{Axis, Slice, array, s};
+//! let array = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+//! let rows = CopyRange { start: 1, end: 3 };
+//! assert_eq!(array.slice_axis(Axis(0), rows.into()), array![[4, 5, 6], [7, 8, 9]]);
+//! assert_eq!(array.slice(s![Slice::from(rows), 0]), array![4, 7]);
+//! assert_eq!(
+//!     array.slice(s![.., Slice::from(CopyRangeInclusive { start: 0, end: -2 })]),
+//!     array![[1, 2], [4, 5], [7, 8]],
+//! );
+//! assert_eq!(Slice::from(CopyRangeFrom { start: 1 }), Slice::from(1..));
+//! assert_eq!(Slice::from(CopyRangeTo { end: 2usize }), Slice::from(..2usize));
+//! assert_eq!(Slice::from(CopyRangeToInclusive { end: -1 }), Slice::from(..));
+//! # }
+//! ```
+//!
+//! [`Slice`]: https://docs.rs/ndarray/0.16/ndarray/struct.Slice.html
+//! [`SliceInfoElem`]: https://docs.rs/ndarray/0.16/ndarray/enum.SliceInfoElem.html
+//! [`s!`]: https://docs.rs/ndarray/0.16/ndarray/macro.s.html
+//!
+//! With the `"num-traits"` feature enabled, `PrimIntRange` provides integer
+//! range operations which are generic over `num_traits::PrimInt`.
+//!
+//! With the `"new_range_api"` feature enabled, the range types are also
+//! convertible to and from their [`core::range`] counterparts. This requires a
+//! toolchain on which `core::range` is stable, which is currently only
+//! nightly.
 
 // Much of this crate is adapted from the stdlib, specifically
 // `library/core/src/ops/range.rs`.
@@ -23,9 +132,43 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-use core::ops::{
-    Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull,
-    RangeInclusive, RangeTo, RangeToInclusive,
+mod any;
+pub mod bound;
+mod bounds;
+mod convert;
+mod difference;
+mod error;
+mod fmt;
+mod iter;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "new_range_api")]
+mod new_range;
+#[cfg(feature = "num-traits")]
+mod num;
+mod one_sided;
+mod parse;
+mod slice;
+mod step;
+
+pub use any::AnyCopyRange;
+pub use bounds::{CopyBounds, CopyIntoBounds, intersect_bounds};
+pub use difference::{Difference, InclusiveDifference};
+pub use error::{IndexRangeError, ParseCopyRangeError, TryFromBoundsError};
+pub use iter::{CheckedFromIter, CopyStepBy, InclusiveChunks, UncheckedIter};
+#[cfg(feature = "num-traits")]
+pub use num::PrimIntRange;
+pub use one_sided::{OneSidedCopyRange, SplitDirection, split_slice_at};
+pub use slice::{Sliceable, copy_get_disjoint_mut};
+pub use step::CopyStep;
+
+use core::{
+    cmp::Ordering,
+    num::{NonZeroU64, TryFromIntError},
+    ops::{
+        Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull,
+        RangeInclusive, RangeTo, RangeToInclusive,
+    },
 };
 
 /// A (half-open) range bounded inclusively below and exclusively above. See
@@ -33,7 +176,79 @@ use core::ops::{
 ///
 /// Unlike `Range`, this struct is `Copy` if `Idx` is `Copy`, and implements
 /// `IntoIterator` instead of `Interator`.
-#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+///
+/// Like the other range types, it implements `Display`, `LowerHex`,
+/// `UpperHex`, `Binary` and `Octal` by formatting each endpoint, with the
+/// same separator as `Debug`.
+///
+/// Its `Debug` output is `start..end`, like `Range`, but with the alternate
+/// flag (`{:#?}`) it is formatted like a struct instead, which is clearer
+/// when pretty-printing larger structures:
+///
+/// ```rust
+/// # use copy_range::CopyRange;
+/// let range = CopyRange { start: -3, end: -1 };
+/// assert_eq!(format!("{range:?}"), "-3..-1");
+/// assert_eq!(
+///     format!("{range:#?}"),
+///     "CopyRange {\n    start: -3,\n    end: -1,\n}"
+/// );
+/// ```
+///
+/// It can be iterated over whenever `Range<Idx>` is an iterator, which
+/// includes ranges of `char` on stable Rust. Like `Range<char>`, this skips
+/// the surrogate code points, which are not valid `char`s:
+///
+/// ```rust
+/// # use copy_range::CopyRange;
+/// let letters: String =
+///     CopyRange { start: 'a', end: 'f' }.into_iter().collect();
+/// assert_eq!(letters, "abcde");
+/// let greek: String =
+///     CopyRange { start: 'α', end: 'ε' }.into_iter().collect();
+/// assert_eq!(greek, "αβγδ");
+/// let range = CopyRange { start: '\u{D7FF}', end: '\u{E001}' };
+/// assert!(range.into_iter().eq(['\u{D7FF}', '\u{E000}']));
+/// ```
+///
+/// Unlike `Range`, it implements `PartialOrd` and `Ord`, ordering ranges
+/// lexicographically by `start`, then by `end`. This is not an ordering by
+/// containment or position: overlapping ranges are simply ordered by their
+/// starts, and a reversed (empty) range is ordered by its endpoints like any
+/// other. The other range types are ordered the same way, by their fields in
+/// the order `start`, `end`.
+///
+/// ```rust
+/// # use copy_range::CopyRange;
+/// use std::collections::BTreeMap;
+/// let range = |start, end| CopyRange { start, end };
+/// assert!(range(1, 5) < range(2, 3));
+/// assert!(range(1, 3) < range(1, 5));
+///
+/// let mut ranges =
+///     vec![range(5, 6), range(0, 10), range(3, 4), range(0, 2), range(7, 1)];
+/// ranges.sort();
+/// assert_eq!(
+///     ranges,
+///     [range(0, 2), range(0, 10), range(3, 4), range(5, 6), range(7, 1)]
+/// );
+/// assert!(ranges.is_sorted_by_key(|range| range.start));
+///
+/// let mut owners = BTreeMap::new();
+/// owners.insert(range(200u32, 450), "alice");
+/// owners.insert(range(0, 100), "bob");
+/// owners.insert(range(100, 200), "carol");
+/// assert_eq!(
+///     owners.values().copied().collect::<Vec<_>>(),
+///     ["bob", "carol", "alice"]
+/// );
+/// assert_eq!(owners.get(&range(100, 200)), Some(&"carol"));
+/// assert_eq!(
+///     owners.range(range(100, 0)..).next(),
+///     Some((&range(100, 200), &"carol"))
+/// );
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CopyRange<Idx> {
     pub start: Idx,
     pub end: Idx,
@@ -41,6 +256,13 @@ pub struct CopyRange<Idx> {
 
 impl<Idx: core::fmt::Debug> core::fmt::Debug for CopyRange<Idx> {
     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if fmt.alternate() {
+            return fmt
+                .debug_struct("CopyRange")
+                .field("start", &self.start)
+                .field("end", &self.end)
+                .finish();
+        }
         self.start.fmt(fmt)?;
         write!(fmt, "..")?;
         self.end.fmt(fmt)?;
@@ -49,9 +271,81 @@ impl<Idx: core::fmt::Debug> core::fmt::Debug for CopyRange<Idx> {
 }
 
 impl<Idx> CopyRange<Idx> {
+    /// Returns the empty range `Idx::default()..Idx::default()`, e.g. `0..0`
+    /// for integers.
+    ///
+    /// This is the same as `CopyRange::default()`, and
+    /// [`is_empty`](Self::is_empty) returns `true` for it. It can be used as
+    /// the starting point for building up a range with `expand_to_include`:
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let empty = CopyRange::<u32>::empty();
+    /// assert_eq!(empty, CopyRange { start: 0, end: 0 });
+    /// assert!(empty.is_empty());
+    ///
+    /// let hull =
+    ///     [7, 3, 9].into_iter().try_fold(CopyRange::<u32>::empty(), |hull, x| {
+    ///         hull.expand_to_include(x)
+    ///     });
+    /// assert_eq!(hull, Some(CopyRange { start: 3, end: 10 }));
+    /// ```
+    pub fn empty() -> Self
+    where
+        Idx: Default,
+    {
+        CopyRange { start: Idx::default(), end: Idx::default() }
+    }
+
+    /// Returns the empty range `x..x`.
+    ///
+    /// [`is_empty`](Self::is_empty) returns `true` for it, and it contains
+    /// nothing, not even `x`.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// const EMPTY: CopyRange<char> = CopyRange::empty_at('m');
+    /// assert_eq!(EMPTY, CopyRange { start: 'm', end: 'm' });
+    /// assert!(EMPTY.is_empty());
+    /// assert!(!EMPTY.contains(&'m'));
+    /// ```
+    pub const fn empty_at(x: Idx) -> Self
+    where
+        Idx: Copy,
+    {
+        CopyRange { start: x, end: x }
+    }
+
     /// Returns `true` if `item` is contained in the range.
     ///
     /// See [`Range::contains`][core::ops::Range::contains].
+    ///
+    /// For floating-point ranges, NaN is never contained, and a range with a
+    /// NaN endpoint contains nothing, since every comparison with NaN is
+    /// `false`:
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let nan = f64::NAN;
+    /// assert!(!CopyRange { start: 0.0, end: 1.0 }.contains(&nan));
+    /// assert!(
+    ///     !CopyRange { start: f64::NEG_INFINITY, end: f64::INFINITY }
+    ///         .contains(&nan)
+    /// );
+    /// for range in [
+    ///     CopyRange { start: nan, end: 1.0 },
+    ///     CopyRange { start: 0.0, end: nan },
+    ///     CopyRange { start: nan, end: nan },
+    /// ] {
+    ///     for item in [f64::NEG_INFINITY, -1.0, 0.0, 0.5, 1.0, f64::INFINITY, nan]
+    ///     {
+    ///         assert!(!range.contains(&item));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Ranges of integers also have `contains_branchless`, which gives the
+    /// same results.
     pub fn contains<U>(&self, item: &U) -> bool
     where
         Idx: PartialOrd<U>,
@@ -60,6 +354,26 @@ impl<Idx> CopyRange<Idx> {
         <Self as RangeBounds<Idx>>::contains(self, item)
     }
 
+    /// Returns `true` if every item in `items` is contained in the range.
+    ///
+    /// This stops at the first item which is not contained, and returns
+    /// `true` if `items` is empty.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let range = CopyRange { start: 0, end: 10 };
+    /// assert!(range.contains_all([0, 3, 9]));
+    /// assert!(!range.contains_all([0, 10, 3]));
+    /// assert!(range.contains_all([]));
+    /// ```
+    pub fn contains_all<I>(&self, items: I) -> bool
+    where
+        I: IntoIterator<Item = Idx>,
+        Idx: PartialOrd,
+    {
+        items.into_iter().all(|item| self.contains(&item))
+    }
+
     /// Convert a [`Range`] into a `CopyRange`.
     pub fn from_std(range: Range<Idx>) -> Self {
         range.into()
@@ -70,9 +384,224 @@ impl<Idx> CopyRange<Idx> {
         self.into()
     }
 
+    /// Feeds the range into `state` exactly as the equivalent [`Range`] would
+    /// be.
+    ///
+    /// `CopyRange`'s own [`Hash`](core::hash::Hash) impl is guaranteed to hash
+    /// `start` and then `end`, and nothing else. `Range`'s `Hash` impl
+    /// currently does the same, so the two produce the same hashes, but the
+    /// standard library does not document this, so it could change. Use this
+    /// method instead of `Hash::hash` where hashes must match `Range`'s, e.g.
+    /// to look up a `CopyRange` in a table keyed by `Range`s using the same
+    /// hashing logic.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// use std::hash::{BuildHasher, Hasher, RandomState};
+    /// let state = RandomState::new();
+    /// let range = CopyRange { start: 3usize, end: 7 };
+    ///
+    /// let mut hasher = state.build_hasher();
+    /// range.hash_as_std(&mut hasher);
+    /// assert_eq!(hasher.finish(), state.hash_one(3usize..7));
+    ///
+    /// // The same as `Hash::hash` on the `CopyRange` itself, today.
+    /// assert_eq!(state.hash_one(range), state.hash_one(3usize..7));
+    /// ```
+    pub fn hash_as_std<H: core::hash::Hasher>(&self, state: &mut H)
+    where
+        Idx: core::hash::Hash,
+    {
+        core::hash::Hash::hash(
+            &Range { start: &self.start, end: &self.end },
+            state,
+        );
+    }
+
+    /// Creates a range from its start and end.
+    ///
+    /// This is the same as `CopyRange { start, end }`, and does not check that
+    /// `start <= end`.
+    pub fn from_parts(start: Idx, end: Idx) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates a range from any [`RangeBounds`], cloning its bounds.
+    ///
+    /// An included end bound is converted to an excluded one with
+    /// [`CopyStep::checked_successor`], and an excluded start bound to an
+    /// included one. Returns `None` if either bound is unbounded, or if that
+    /// adjustment overflows.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeInclusive};
+    /// # use core::ops::Bound::{Excluded, Included};
+    /// let from = |start, end| Some(CopyRange { start, end });
+    /// assert_eq!(CopyRange::try_from_bounds(&(2u64..5)), from(2, 5));
+    /// assert_eq!(CopyRange::try_from_bounds(&(2u64..=5)), from(2, 6));
+    /// assert_eq!(
+    ///     CopyRange::try_from_bounds(&(Excluded(2u64), Included(5))),
+    ///     from(3, 6)
+    /// );
+    /// assert_eq!(
+    ///     CopyRange::try_from_bounds(&CopyRange { start: 2u64, end: 5 }),
+    ///     from(2, 5)
+    /// );
+    /// assert_eq!(
+    ///     CopyRange::try_from_bounds(&CopyRangeInclusive { start: 2u64, end: 5 }),
+    ///     from(2, 6),
+    /// );
+    /// assert_eq!(CopyRange::<u64>::try_from_bounds(&(2..)), None);
+    /// assert_eq!(CopyRange::<u64>::try_from_bounds(&(..5)), None);
+    /// assert_eq!(CopyRange::<u64>::try_from_bounds(&(..=5)), None);
+    /// assert_eq!(CopyRange::<u64>::try_from_bounds(&..), None);
+    /// assert_eq!(CopyRange::try_from_bounds(&(0..=u64::MAX)), None);
+    /// ```
+    pub fn try_from_bounds<R>(range: &R) -> Option<Self>
+    where
+        R: RangeBounds<Idx> + ?Sized,
+        Idx: CopyStep + Clone,
+    {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        Self::try_from(bounds).ok()
+    }
+
+    /// Returns the start and end of the range, like
+    /// [`RangeInclusive::into_inner`].
+    pub fn into_parts(self) -> (Idx, Idx) {
+        (self.start, self.end)
+    }
+
+    /// Applies `f` to both endpoints of the range.
+    ///
+    /// If `f` is not monotonic, the returned range may be empty (reversed)
+    /// even if `self` is not.
+    ///
+    /// Ranges of integers can also be converted losslessly with `From`
+    /// whenever the integer types can.
+    pub fn map<U, F>(self, mut f: F) -> CopyRange<U>
+    where
+        F: FnMut(Idx) -> U,
+    {
+        CopyRange { start: f(self.start), end: f(self.end) }
+    }
+
+    /// Applies the fallible function `f` to both endpoints of the range,
+    /// returning the first error if either fails.
+    ///
+    /// If `f` is not monotonic, the returned range may be empty (reversed)
+    /// even if `self` is not.
+    ///
+    /// Ranges of integers can also be converted with `TryFrom` whenever the
+    /// integer types can, failing if either endpoint does not fit.
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<CopyRange<U>, E>
+    where
+        F: FnMut(Idx) -> Result<U, E>,
+    {
+        Ok(CopyRange { start: f(self.start)?, end: f(self.end)? })
+    }
+
+    /// Borrows both endpoints of the range.
+    ///
+    /// The returned range implements [`RangeBounds<Idx>`], so this can be used
+    /// to call `RangeBounds`-generic APIs without moving or cloning the
+    /// endpoints.
+    pub fn as_ref(&self) -> CopyRange<&Idx> {
+        CopyRange { start: &self.start, end: &self.end }
+    }
+
+    /// Borrows both endpoints of the range.
+    ///
+    /// This is the same as [`as_ref`][Self::as_ref], and is provided for
+    /// symmetry with [`each_mut`][Self::each_mut], like
+    /// [`<[T; N]>::each_ref`][array::each_ref].
+    pub fn each_ref(&self) -> CopyRange<&Idx> {
+        self.as_ref()
+    }
+
+    /// Mutably borrows both endpoints of the range.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let mut range = CopyRange { start: 1, end: 4 };
+    /// let CopyRange { start, end } = range.each_mut();
+    /// for endpoint in [start, end] {
+    ///     *endpoint *= 10;
+    /// }
+    /// assert_eq!(range, CopyRange { start: 10, end: 40 });
+    /// ```
+    pub fn each_mut(&mut self) -> CopyRange<&mut Idx> {
+        CopyRange { start: &mut self.start, end: &mut self.end }
+    }
+
+    /// Returns a copy of the range with `start` replaced.
+    pub fn with_start(self, start: Idx) -> Self {
+        Self { start, ..self }
+    }
+
+    /// Returns a copy of the range with `end` replaced.
+    pub fn with_end(self, end: Idx) -> Self {
+        Self { end, ..self }
+    }
+
+    /// Discards the end of the range, returning the range `start..`.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeFrom};
+    /// let range = CopyRange { start: 3, end: 8 };
+    /// assert_eq!(range.to_from(), CopyRangeFrom { start: 3 });
+    /// assert_eq!(range.to_from(), 3..);
+    /// ```
+    pub fn to_from(self) -> CopyRangeFrom<Idx> {
+        CopyRangeFrom { start: self.start }
+    }
+
+    /// Applies `f` to the start of the range, leaving the rest unchanged.
+    pub fn map_start<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Idx) -> Idx,
+    {
+        Self { start: f(self.start), ..self }
+    }
+
+    /// Applies `f` to the end of the range, leaving the rest unchanged.
+    ///
+    /// E.g. `range.map_end(|end| end.min(cap))` clamps just the end.
+    pub fn map_end<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Idx) -> Idx,
+    {
+        Self { end: f(self.end), ..self }
+    }
+
+    /// Returns the start and end bounds of the range.
+    ///
+    /// This is like [`RangeBounds::start_bound`] and
+    /// [`RangeBounds::end_bound`], but returns owned bounds.
+    pub fn to_bounds(&self) -> (Bound<Idx>, Bound<Idx>)
+    where
+        Idx: Copy,
+    {
+        (Bound::Included(self.start), Bound::Excluded(self.end))
+    }
+
     /// Returns `true` if the range contains no items.
     ///
-    /// See [`Range::contains`][core::ops::Range::contains].
+    /// This is `start >= end`, which differs from
+    /// [`Range::is_empty`][core::ops::Range::is_empty] if either endpoint is
+    /// incomparable, e.g. NaN. Such a range is not considered empty, even
+    /// though [`contains`](Self::contains) is `false` for every item. Use
+    /// [`is_empty_total`](Self::is_empty_total) to treat it as empty instead.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// assert!(!CopyRange { start: 0.0, end: 1.0 }.is_empty());
+    /// assert!(CopyRange { start: 1.0, end: 1.0 }.is_empty());
+    /// // `start >= end` is `false` if either endpoint is NaN.
+    /// assert!(!CopyRange { start: f64::NAN, end: 1.0 }.is_empty());
+    /// assert!(!CopyRange { start: 0.0, end: f64::NAN }.is_empty());
+    /// assert!(!CopyRange { start: f64::NAN, end: f64::NAN }.is_empty());
+    /// ```
     pub fn is_empty(&self) -> bool
     where
         Idx: PartialOrd,
@@ -80,13 +609,368 @@ impl<Idx> CopyRange<Idx> {
         self.start >= self.end
     }
 
-    /// Returns the exact length of the range.
-    pub fn len(&self) -> usize
+    /// Returns `true` if the range contains no items, treating a range with
+    /// an incomparable endpoint, e.g. NaN, as empty.
+    ///
+    /// This is `!(start < end)`, like
+    /// [`Range::is_empty`][core::ops::Range::is_empty], so unlike
+    /// [`is_empty`](Self::is_empty) it agrees with
+    /// [`contains`](Self::contains), which is `false` for every item in such
+    /// a range. For totally ordered types, the two are the same.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// assert!(!CopyRange { start: 0.0, end: 1.0 }.is_empty_total());
+    /// assert!(CopyRange { start: 1.0, end: 0.0 }.is_empty_total());
+    ///
+    /// let nan_start = CopyRange { start: f64::NAN, end: 1.0 };
+    /// let nan_end = CopyRange { start: 0.0, end: f64::NAN };
+    /// let nan_both = CopyRange { start: f64::NAN, end: f64::NAN };
+    /// for range in [nan_start, nan_end, nan_both] {
+    ///     assert!(range.is_empty_total());
+    ///     assert!(!range.is_empty());
+    ///     assert!(!range.contains(&0.5));
+    /// }
+    /// ```
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    pub fn is_empty_total(&self) -> bool
     where
-        core::ops::Range<Idx>: ExactSizeIterator,
-        Self: Copy,
+        Idx: PartialOrd,
     {
-        self.into_std().len()
+        !(self.start < self.end)
+    }
+
+    /// Returns the range with its endpoints swapped if `start > end`, so
+    /// that the result is not reversed.
+    ///
+    /// Note that this changes which elements the range contains: a reversed
+    /// range is empty, but (unless its endpoints are equal) the swapped range
+    /// is not. Only use this if reversed ranges should be treated as if they
+    /// were written the other way around, e.g. for user input.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// assert_eq!(
+    ///     CopyRange { start: 5, end: 2 }.normalized(),
+    ///     CopyRange { start: 2, end: 5 }
+    /// );
+    /// assert_eq!(
+    ///     CopyRange { start: 2, end: 5 }.normalized(),
+    ///     CopyRange { start: 2, end: 5 }
+    /// );
+    /// assert_eq!(
+    ///     CopyRange { start: 3, end: 3 }.normalized(),
+    ///     CopyRange { start: 3, end: 3 }
+    /// );
+    /// assert!(CopyRange { start: 5, end: 2 }.is_empty());
+    /// assert!(!CopyRange { start: 5, end: 2 }.normalized().is_empty());
+    /// ```
+    pub fn normalized(self) -> Self
+    where
+        Idx: Ord,
+    {
+        if self.start > self.end {
+            Self { start: self.end, end: self.start }
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` if `start <= end`, i.e. if the range is not reversed.
+    ///
+    /// A well-formed range may still be empty, if `start == end`. Ranges with
+    /// incomparable endpoints (e.g. NaN) are not well-formed.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// assert!(CopyRange { start: 2, end: 5 }.is_well_formed());
+    /// assert!(!CopyRange { start: 5, end: 2 }.is_well_formed());
+    /// let range = CopyRange { start: 3, end: 3 };
+    /// assert!(range.is_well_formed() && range.is_empty());
+    /// assert!(!CopyRange { start: 0.0, end: f64::NAN }.is_well_formed());
+    /// ```
+    pub fn is_well_formed(&self) -> bool
+    where
+        Idx: PartialOrd,
+    {
+        self.start <= self.end
+    }
+
+    /// Returns `true` if `self` and `other` have any elements in common.
+    ///
+    /// Ranges which only touch at an endpoint (e.g. `0..5` and `5..10`) do not
+    /// overlap, and empty ranges do not overlap anything.
+    pub fn overlaps(&self, other: &CopyRange<Idx>) -> bool
+    where
+        Idx: PartialOrd,
+    {
+        self.start < self.end
+            && other.start < other.end
+            && self.start < other.end
+            && other.start < self.end
+    }
+
+    /// Returns `true` if `self` and the inclusive range `other` have any
+    /// elements in common.
+    ///
+    /// This compares the bounds directly, so it does not require converting
+    /// either range, and empty ranges do not overlap anything.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeInclusive};
+    /// // Every pair of `u8` ranges with endpoints near either end of the type,
+    /// // including empty and reversed ranges, checked against the elements each
+    /// // one contains.
+    /// let points = (0..=4).chain(251..=u8::MAX);
+    /// for start in points.clone() {
+    ///     for end in points.clone() {
+    ///         let exclusive = CopyRange { start, end };
+    ///         for other_start in points.clone() {
+    ///             for other_end in points.clone() {
+    ///                 let inclusive = CopyRangeInclusive {
+    ///                     start: other_start,
+    ///                     end: other_end,
+    ///                 };
+    ///                 let expected = (0..=u8::MAX).any(|x| {
+    ///                     exclusive.contains(&x) && inclusive.contains(&x)
+    ///                 });
+    ///                 assert_eq!(
+    ///                     exclusive.overlaps_inclusive(&inclusive),
+    ///                     expected
+    ///                 );
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn overlaps_inclusive(&self, other: &CopyRangeInclusive<Idx>) -> bool
+    where
+        Idx: PartialOrd,
+    {
+        self.start < self.end
+            && other.start <= other.end
+            && self.start <= other.end
+            && other.start < self.end
+    }
+
+    /// Returns the elements of `self` which are also in `other`.
+    ///
+    /// This is the same as [`other.intersect(self)`](CopyRangeFrom::intersect),
+    /// and similarly never returns a reversed range.
+    pub fn intersect_from(&self, other: &CopyRangeFrom<Idx>) -> CopyRange<Idx>
+    where
+        Idx: Ord + Copy,
+    {
+        other.intersect(self)
+    }
+
+    /// Narrows `self` in place to the elements it has in common with
+    /// `other`, returning `true` if there are any.
+    ///
+    /// Afterwards, `self` is always `max(start, other.start)..min(end,
+    /// other.end)`. If this returns `false`, that range is empty, and may be
+    /// reversed.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let mut range = CopyRange { start: 0, end: 100 };
+    /// assert!(range.intersect_with(&CopyRange { start: 20, end: 200 }));
+    /// assert!(range.intersect_with(&CopyRange { start: 0, end: 50 }));
+    /// assert_eq!(range, CopyRange { start: 20, end: 50 });
+    /// assert!(!range.intersect_with(&CopyRange { start: 60, end: 70 }));
+    /// assert_eq!(range, CopyRange { start: 60, end: 50 });
+    /// assert!(range.is_empty());
+    /// ```
+    pub fn intersect_with(&mut self, other: &CopyRange<Idx>) -> bool
+    where
+        Idx: Ord + Copy,
+    {
+        self.start = Ord::max(self.start, other.start);
+        self.end = Ord::min(self.end, other.end);
+        self.start < self.end
+    }
+
+    /// Collects the elements of the range into an array, if the range has
+    /// exactly `N` elements.
+    ///
+    /// Returns `None` if the range has more or fewer than `N` elements.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let range = CopyRange { start: 10usize, end: 14 };
+    /// assert_eq!(range.try_collect_array::<4>(), Some([10, 11, 12, 13]));
+    ///
+    /// let range = CopyRange { start: 10usize, end: 15 };
+    /// assert_eq!(range.try_collect_array::<4>(), None);
+    /// ```
+    pub fn try_collect_array<const N: usize>(self) -> Option<[Idx; N]>
+    where
+        Range<Idx>: Iterator<Item = Idx>,
+    {
+        let mut iter = self.into_iter();
+        // `Range`'s `size_hint` is exact whenever the length fits in a `usize`.
+        if iter.size_hint() != (N, Some(N)) {
+            return None;
+        }
+        Some(core::array::from_fn(|_| iter.next().unwrap()))
+    }
+
+    /// Returns an iterator over the elements of the range, without consuming
+    /// it.
+    ///
+    /// This is equivalent to `(*self).into_iter()`, since the range is `Copy`.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let range = CopyRange { start: 1, end: 7 };
+    /// let evens: Vec<i32> =
+    ///     range.iter().filter(|i| i % 2 == 0).map(|i| i * 10).collect();
+    /// assert_eq!(evens, [20, 40, 60]);
+    /// // `range` was not consumed, so it can be iterated again.
+    /// assert_eq!(range.iter().count(), 6);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn iter(&self) -> Range<Idx>
+    where
+        Idx: Copy,
+        Range<Idx>: Iterator<Item = Idx>,
+    {
+        (*self).into_iter()
+    }
+
+    /// Returns an iterator over the elements of the range, each paired with
+    /// its offset from `start`.
+    ///
+    /// This is equivalent to `self.into_iter().enumerate()`. An empty range
+    /// yields nothing.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let range = CopyRange { start: 10, end: 13 };
+    /// let pairs: Vec<(usize, i32)> = range.indexed().collect();
+    /// assert_eq!(pairs, [(0, 10), (1, 11), (2, 12)]);
+    /// ```
+    pub fn indexed(self) -> core::iter::Enumerate<Range<Idx>>
+    where
+        Range<Idx>: Iterator<Item = Idx>,
+    {
+        self.into_iter().enumerate()
+    }
+
+    /// Returns the elements of `self` which are not contained in `other`.
+    ///
+    /// The result consists of the part of `self` before `other` and the part
+    /// of `self` after `other`, either of which may be absent. If `other` is
+    /// empty, the whole of `self` is before it.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, Difference};
+    /// let r = |start, end| CopyRange { start, end };
+    /// let diff = |before, after| Difference { before, after };
+    /// let cases = [
+    ///     // `other` is inside `self`.
+    ///     (r(0, 10), r(3, 6), diff(Some(r(0, 3)), Some(r(6, 10)))),
+    ///     // `other` overlaps the start or the end of `self`.
+    ///     (r(0, 10), r(-5, 4), diff(None, Some(r(4, 10)))),
+    ///     (r(0, 10), r(6, 15), diff(Some(r(0, 6)), None)),
+    ///     // `other` covers `self`, including exactly.
+    ///     (r(0, 10), r(-5, 15), diff(None, None)),
+    ///     (r(0, 10), r(0, 10), diff(None, None)),
+    ///     // `other` shares an endpoint with `self`.
+    ///     (r(0, 10), r(0, 4), diff(None, Some(r(4, 10)))),
+    ///     (r(0, 10), r(6, 10), diff(Some(r(0, 6)), None)),
+    ///     // `other` is entirely before, after or adjacent to `self`.
+    ///     (r(0, 10), r(-5, -1), diff(None, Some(r(0, 10)))),
+    ///     (r(0, 10), r(10, 15), diff(Some(r(0, 10)), None)),
+    ///     // Either range is empty or reversed.
+    ///     (r(0, 10), r(5, 5), diff(Some(r(0, 10)), None)),
+    ///     (r(0, 10), r(6, 3), diff(Some(r(0, 10)), None)),
+    ///     (r(5, 5), r(0, 10), diff(None, None)),
+    ///     (r(10, 0), r(3, 6), diff(None, None)),
+    /// ];
+    /// for (a, b, expected) in cases {
+    ///     assert_eq!(a.difference(&b), expected, "{a:?} - {b:?}");
+    ///     // The pieces are exactly the elements of `a` not in `b`.
+    ///     let remaining: Vec<i32> =
+    ///         a.into_iter().filter(|x| !b.contains(x)).collect();
+    ///     let pieces: Vec<i32> = expected.into_iter().flatten().collect();
+    ///     assert_eq!(pieces, remaining);
+    /// }
+    /// ```
+    pub fn difference(&self, other: &Self) -> Difference<Idx>
+    where
+        Idx: Ord + Copy,
+    {
+        if self.is_empty() || other.is_empty() {
+            return Difference {
+                before: (!self.is_empty()).then_some(*self),
+                after: None,
+            };
+        }
+        let before = (self.start < other.start).then(|| CopyRange {
+            start: self.start,
+            end: Ord::min(self.end, other.start),
+        });
+        let after = (other.end < self.end).then(|| CopyRange {
+            start: Ord::max(self.start, other.end),
+            end: self.end,
+        });
+        Difference { before, after }
+    }
+
+    /// Returns `true` if every element of `other` is contained in `self`.
+    ///
+    /// Bounds are compared without assuming anything about the elements
+    /// between them, so for integers, e.g. `(Excluded(4), Excluded(8))` is not
+    /// considered to be enclosed by `5..8`, even though it has the same
+    /// elements. An `other` whose bounds cross (e.g. `5..=3`) is empty, and so
+    /// is enclosed by any range.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// # use core::ops::Bound::*;
+    /// let range = CopyRange { start: 5, end: 10 };
+    /// assert!(range.encloses(&(5..10)));
+    /// assert!(range.encloses(&(6..=9)));
+    /// assert!(range.encloses(&(Excluded(5), Excluded(10))));
+    /// assert!(!range.encloses(&(5..=10)));
+    /// assert!(!range.encloses(&(4..10)));
+    /// assert!(!range.encloses(&(5..)));
+    /// assert!(!range.encloses(&(..10)));
+    /// assert!(range.encloses(&(20..0)));
+    /// ```
+    pub fn encloses<R>(&self, other: &R) -> bool
+    where
+        Idx: PartialOrd,
+        R: ?Sized + RangeBounds<Idx>,
+    {
+        range_encloses(self, other)
+    }
+
+    /// Returns `true` if `self` and `other` have any elements in common.
+    ///
+    /// Like [`encloses`](Self::encloses), bounds are compared without
+    /// assuming anything about the elements between them, so for integers,
+    /// e.g. `(Excluded(4), Excluded(5))` is considered to intersect `0..10`.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// # use core::ops::Bound::*;
+    /// let range = CopyRange { start: 5, end: 10 };
+    /// assert!(range.intersects(&(9..)));
+    /// assert!(range.intersects(&(..=5)));
+    /// assert!(range.intersects(&(Excluded(0), Excluded(6))));
+    /// assert!(!range.intersects(&(10..)));
+    /// assert!(!range.intersects(&(..5)));
+    /// assert!(!range.intersects(&(Excluded(10), Unbounded)));
+    /// assert!(!range.intersects(&(7..7)));
+    /// ```
+    pub fn intersects<R>(&self, other: &R) -> bool
+    where
+        Idx: PartialOrd,
+        R: ?Sized + RangeBounds<Idx>,
+    {
+        range_intersects(self, other)
     }
 }
 
@@ -104,17 +988,92 @@ impl<Idx> From<CopyRange<Idx>> for Range<Idx> {
     }
 }
 
+/// Convert a `(start, end)` tuple into a `CopyRange`.
+///
+/// This does not check that `start <= end`.
+impl<Idx> From<(Idx, Idx)> for CopyRange<Idx> {
+    fn from((start, end): (Idx, Idx)) -> Self {
+        CopyRange { start, end }
+    }
+}
+
+/// Convert a `CopyRange` into a `(start, end)` tuple.
+impl<Idx> From<CopyRange<Idx>> for (Idx, Idx) {
+    fn from(value: CopyRange<Idx>) -> Self {
+        (value.start, value.end)
+    }
+}
+
+/// Compare a `CopyRange` with a [`Range`] field-by-field.
+///
+/// ```rust
+/// # use copy_range::CopyRange;
+/// assert_eq!(CopyRange { start: 3, end: 7 }, 3..7);
+/// assert_eq!(3..7, CopyRange { start: 3, end: 7 });
+/// assert_ne!(CopyRange { start: 3, end: 7 }, 3..8);
+/// assert_ne!(2..7, CopyRange { start: 3, end: 7 });
+/// ```
+impl<Idx: PartialEq> PartialEq<Range<Idx>> for CopyRange<Idx> {
+    fn eq(&self, other: &Range<Idx>) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+/// Compare a [`Range`] with a `CopyRange` field-by-field.
+impl<Idx: PartialEq> PartialEq<CopyRange<Idx>> for Range<Idx> {
+    fn eq(&self, other: &CopyRange<Idx>) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+/// Order a `CopyRange` and a [`Range`] lexicographically by `start`, then
+/// `end`, like two `CopyRange`s.
+///
+/// `Range` itself does not implement `PartialOrd`, so this is an extension
+/// beyond `core`, for comparing a mixture of the two types without
+/// converting them.
+///
+/// ```rust
+/// # use copy_range::CopyRange;
+/// assert!(CopyRange { start: 1, end: 5 } < (2..3));
+/// assert!((1..3) < CopyRange { start: 1, end: 5 });
+/// assert!(CopyRange { start: 1, end: 5 } <= (1..5));
+/// assert_eq!(
+///     CopyRange { start: 1.0, end: 5.0 }.partial_cmp(&(1.0..f64::NAN)),
+///     None
+/// );
+/// ```
+impl<Idx: PartialOrd> PartialOrd<Range<Idx>> for CopyRange<Idx> {
+    fn partial_cmp(&self, other: &Range<Idx>) -> Option<Ordering> {
+        (&self.start, &self.end).partial_cmp(&(&other.start, &other.end))
+    }
+}
+
+/// Order a [`Range`] and a `CopyRange` lexicographically by `start`, then
+/// `end`, like two `CopyRange`s.
+impl<Idx: PartialOrd> PartialOrd<CopyRange<Idx>> for Range<Idx> {
+    fn partial_cmp(&self, other: &CopyRange<Idx>) -> Option<Ordering> {
+        (&self.start, &self.end).partial_cmp(&(&other.start, &other.end))
+    }
+}
+
 /// A range only bounded inclusively below. See [`core::ops::RangeFrom`].
 ///
 /// Unlike `RangeFrom`, this struct is `Copy` if `Idx` is `Copy`, and implements
 /// `IntoIterator` instead of `Interator`.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CopyRangeFrom<Idx> {
     pub start: Idx,
 }
 
 impl<Idx: core::fmt::Debug> core::fmt::Debug for CopyRangeFrom<Idx> {
     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if fmt.alternate() {
+            return fmt
+                .debug_struct("CopyRangeFrom")
+                .field("start", &self.start)
+                .finish();
+        }
         self.start.fmt(fmt)?;
         write!(fmt, "..")?;
         Ok(())
@@ -133,6 +1092,15 @@ impl<Idx> CopyRangeFrom<Idx> {
         <Self as RangeBounds<Idx>>::contains(self, item)
     }
 
+    /// Returns `true`, since a range with no end cannot be reversed.
+    ///
+    /// This is provided for consistency with
+    /// [`CopyRange::is_well_formed`] and
+    /// [`CopyRangeInclusive::is_well_formed`].
+    pub const fn is_well_formed(&self) -> bool {
+        true
+    }
+
     /// Convert a [`RangeFrom`] into a `CopyRangeFrom`.
     pub fn from_std(range: RangeFrom<Idx>) -> Self {
         range.into()
@@ -142,35 +1110,304 @@ impl<Idx> CopyRangeFrom<Idx> {
     pub fn into_std(self) -> RangeFrom<Idx> {
         self.into()
     }
-}
 
-/// Convert a [`RangeFrom`] into a `CopyRangeFrom`.
-impl<Idx> From<RangeFrom<Idx>> for CopyRangeFrom<Idx> {
-    fn from(RangeFrom { start }: RangeFrom<Idx>) -> Self {
-        Self { start }
+    /// Returns an iterator over the elements of the range, without consuming
+    /// it.
+    ///
+    /// This is equivalent to `(*self).into_iter()`, since the range is `Copy`.
+    /// Like iterating over a [`RangeFrom`], the iterator does not stop at the
+    /// maximum value of an integer type; see `iter_checked` for one which
+    /// does.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeFrom;
+    /// let range = CopyRangeFrom { start: 1u32 };
+    /// let squares: Vec<u32> = range.iter().map(|i| i * i).take(4).collect();
+    /// assert_eq!(squares, [1, 4, 9, 16]);
+    /// assert_eq!(range.iter().next(), Some(1));
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn iter(&self) -> RangeFrom<Idx>
+    where
+        Idx: Copy,
+        RangeFrom<Idx>: Iterator<Item = Idx>,
+    {
+        (*self).into_iter()
     }
-}
 
-/// Convert a `CopyRangeFrom` into a [`RangeFrom`].
-impl<Idx> From<CopyRangeFrom<Idx>> for RangeFrom<Idx> {
-    fn from(value: CopyRangeFrom<Idx>) -> Self {
-        value.start..
+    /// Borrows the start of the range.
+    ///
+    /// The returned range implements [`RangeBounds<Idx>`].
+    pub fn as_ref(&self) -> CopyRangeFrom<&Idx> {
+        CopyRangeFrom { start: &self.start }
     }
-}
 
-/// A range bounded inclusively above and below. See
-/// [`core::ops::RangeInclusive`].
-///
-/// Unlike `RangeInclusive`, this struct is `Copy` if `Idx` is `Copy`, and
-/// implements `IntoIterator` instead of `Interator`.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub struct CopyRangeInclusive<Idx> {
-    pub start: Idx,
-    pub end: Idx,
-}
+    /// Borrows the start of the range.
+    ///
+    /// This is the same as [`as_ref`][Self::as_ref], and is provided for
+    /// symmetry with [`each_mut`][Self::each_mut], like
+    /// [`<[T; N]>::each_ref`][array::each_ref].
+    pub fn each_ref(&self) -> CopyRangeFrom<&Idx> {
+        self.as_ref()
+    }
+
+    /// Mutably borrows the start of the range.
+    pub fn each_mut(&mut self) -> CopyRangeFrom<&mut Idx> {
+        CopyRangeFrom { start: &mut self.start }
+    }
+
+    /// Returns a copy of the range with `start` replaced.
+    pub fn with_start(self, start: Idx) -> Self {
+        Self { start }
+    }
+
+    /// Returns the range from `start` to `end`, exclusive, i.e.
+    /// `start..end`.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeFrom};
+    /// let range = CopyRangeFrom::from_std(3..);
+    /// assert_eq!(range.with_end(8), CopyRange { start: 3, end: 8 });
+    /// assert_eq!(range.with_end(8), 3..8);
+    /// ```
+    pub fn with_end(self, end: Idx) -> CopyRange<Idx> {
+        CopyRange { start: self.start, end }
+    }
+
+    /// Returns the range from `start` to `end`, inclusive, i.e.
+    /// `start..=end`.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRangeFrom, CopyRangeInclusive};
+    /// let range = CopyRangeFrom::from_std(3..);
+    /// assert_eq!(
+    ///     range.with_end_inclusive(8),
+    ///     CopyRangeInclusive { start: 3, end: 8 }
+    /// );
+    /// ```
+    pub fn with_end_inclusive(self, end: Idx) -> CopyRangeInclusive<Idx> {
+        CopyRangeInclusive { start: self.start, end }
+    }
+
+    /// Applies `f` to the start of the range, leaving the rest unchanged.
+    pub fn map_start<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Idx) -> Idx,
+    {
+        Self { start: f(self.start) }
+    }
+
+    /// Returns the start and end bounds of the range.
+    ///
+    /// This is like [`RangeBounds::start_bound`] and
+    /// [`RangeBounds::end_bound`], but returns owned bounds.
+    pub fn to_bounds(&self) -> (Bound<Idx>, Bound<Idx>)
+    where
+        Idx: Copy,
+    {
+        (Bound::Included(self.start), Bound::Unbounded)
+    }
+
+    /// Returns `true` if every element of `other` is contained in `self`,
+    /// i.e. if `other` has no elements less than `start`.
+    ///
+    /// Only the start bound of `other` matters, unless `other` is empty, in
+    /// which case it is contained in any range. Like
+    /// [`CopyRange::encloses`], this does not assume anything about which
+    /// values lie between two others, so e.g. for integers,
+    /// `(Excluded(4), Unbounded)` is not considered to be contained in `5..`.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeFrom, CopyRangeInclusive};
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// let from = CopyRangeFrom { start: 5 };
+    /// assert!(from.contains_range(&(5..10)));
+    /// assert!(from.contains_range(&(6..)));
+    /// assert!(from.contains_range(&(5..=10)));
+    /// assert!(!from.contains_range(&(..10)));
+    /// assert!(!from.contains_range(&(..=10)));
+    /// assert!(!from.contains_range(&(..)));
+    /// assert!(from.contains_range(&(Excluded(5), Unbounded)));
+    /// assert!(!from.contains_range(&(Excluded(4), Included(10))));
+    /// assert!(from.contains_range(&CopyRange { start: 7, end: 8 }));
+    /// assert!(!from.contains_range(&CopyRangeInclusive { start: 4, end: 8 }));
+    /// // Empty ranges are contained in any range.
+    /// assert!(from.contains_range(&(0..0)));
+    /// ```
+    pub fn contains_range<R>(&self, other: &R) -> bool
+    where
+        Idx: PartialOrd,
+        R: ?Sized + RangeBounds<Idx>,
+    {
+        range_encloses(self, other)
+    }
+
+    /// Returns `start..end`, or the empty range `end..end` if `start > end`.
+    pub fn bounded_by(&self, end: Idx) -> CopyRange<Idx>
+    where
+        Idx: Ord + Copy,
+    {
+        CopyRange { start: Ord::min(self.start, end), end }
+    }
+
+    /// Returns the elements of `other` which are also in `self`, i.e.
+    /// `max(start, other.start)..other.end`.
+    ///
+    /// If `self` starts after `other` ends, this returns the empty range
+    /// `other.end..other.end` rather than a reversed range, so a range
+    /// which fits within a slice is never turned into one which doesn't:
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeFrom};
+    /// let file = [0u8; 100];
+    /// let extent = CopyRange { start: 0, end: file.len() };
+    /// let request = CopyRangeFrom { start: 40 };
+    /// assert_eq!(file[request.intersect(&extent)].len(), 60);
+    /// let request = CopyRangeFrom { start: 4096 };
+    /// assert_eq!(request.intersect(&extent), CopyRange { start: 100, end: 100 });
+    /// assert!(file[request.intersect(&extent)].is_empty());
+    /// ```
+    pub fn intersect(&self, other: &CopyRange<Idx>) -> CopyRange<Idx>
+    where
+        Idx: Ord + Copy,
+    {
+        let start = Ord::min(Ord::max(self.start, other.start), other.end);
+        CopyRange { start, end: other.end }
+    }
+
+    /// Restricts the range to be within `outer`, returning
+    /// `max(start, outer.start)..outer.end`.
+    ///
+    /// This is the same as [`intersect`][Self::intersect]: if `start` is
+    /// past `outer.end`, the result collapses to the empty range
+    /// `outer.end..outer.end`, so if `outer` can be used to index a slice, so
+    /// can the result, whatever `start` is:
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeFrom};
+    /// let buf = [0u8; 16];
+    /// let outer = CopyRange { start: 4, end: 12 };
+    /// for start in [0, 4, 8, 12, 13, 1000, usize::MAX] {
+    ///     let range = CopyRangeFrom { start }.clamp_to(&outer);
+    ///     assert!(outer.encloses(&range));
+    ///     let _ = &buf[range];
+    /// }
+    /// ```
+    pub fn clamp_to(&self, outer: &CopyRange<Idx>) -> CopyRange<Idx>
+    where
+        Idx: Ord + Copy,
+    {
+        self.intersect(outer)
+    }
+
+    /// Returns the elements of `other` which are also in `self`, i.e.
+    /// `max(start, other.start)..=other.end`, or `None` if there are none.
+    pub fn intersect_inclusive(
+        &self,
+        other: &CopyRangeInclusive<Idx>,
+    ) -> Option<CopyRangeInclusive<Idx>>
+    where
+        Idx: Ord + Copy,
+    {
+        let start = Ord::max(self.start, other.start);
+        (start <= other.end)
+            .then_some(CopyRangeInclusive { start, end: other.end })
+    }
+}
+
+/// Convert a [`RangeFrom`] into a `CopyRangeFrom`.
+impl<Idx> From<RangeFrom<Idx>> for CopyRangeFrom<Idx> {
+    fn from(RangeFrom { start }: RangeFrom<Idx>) -> Self {
+        Self { start }
+    }
+}
+
+/// Convert a `CopyRangeFrom` into a [`RangeFrom`].
+impl<Idx> From<CopyRangeFrom<Idx>> for RangeFrom<Idx> {
+    fn from(value: CopyRangeFrom<Idx>) -> Self {
+        value.start..
+    }
+}
+
+/// Compare a `CopyRangeFrom` with a [`RangeFrom`].
+///
+/// ```rust
+/// # use copy_range::CopyRangeFrom;
+/// assert_eq!(CopyRangeFrom { start: 3 }, 3..);
+/// assert_eq!(3.., CopyRangeFrom { start: 3 });
+/// assert_ne!(CopyRangeFrom { start: 3 }, 4..);
+/// ```
+impl<Idx: PartialEq> PartialEq<RangeFrom<Idx>> for CopyRangeFrom<Idx> {
+    fn eq(&self, other: &RangeFrom<Idx>) -> bool {
+        self.start == other.start
+    }
+}
+
+/// Compare a [`RangeFrom`] with a `CopyRangeFrom`.
+impl<Idx: PartialEq> PartialEq<CopyRangeFrom<Idx>> for RangeFrom<Idx> {
+    fn eq(&self, other: &CopyRangeFrom<Idx>) -> bool {
+        self.start == other.start
+    }
+}
+
+/// Order a `CopyRangeFrom` and a [`RangeFrom`] by `start`.
+///
+/// `RangeFrom` itself does not implement `PartialOrd`, so this is an
+/// extension beyond `core`.
+impl<Idx: PartialOrd> PartialOrd<RangeFrom<Idx>> for CopyRangeFrom<Idx> {
+    fn partial_cmp(&self, other: &RangeFrom<Idx>) -> Option<Ordering> {
+        self.start.partial_cmp(&other.start)
+    }
+}
+
+/// Order a [`RangeFrom`] and a `CopyRangeFrom` by `start`.
+impl<Idx: PartialOrd> PartialOrd<CopyRangeFrom<Idx>> for RangeFrom<Idx> {
+    fn partial_cmp(&self, other: &CopyRangeFrom<Idx>) -> Option<Ordering> {
+        self.start.partial_cmp(&other.start)
+    }
+}
+
+/// A range bounded inclusively above and below. See
+/// [`core::ops::RangeInclusive`].
+///
+/// Unlike `RangeInclusive`, this struct is `Copy` if `Idx` is `Copy`, and
+/// implements `IntoIterator` instead of `Interator`.
+///
+/// Note that the `Default` value has `start` and `end` both equal to
+/// `Idx::default()`, so it contains exactly one element, unlike the default
+/// [`CopyRange`], which is empty:
+///
+/// ```rust
+/// # use copy_range::{CopyRange, CopyRangeFrom, CopyRangeInclusive};
+/// #[derive(Default)]
+/// struct Spans {
+///     range: CopyRange<u32>,
+///     from: CopyRangeFrom<u32>,
+///     inclusive: CopyRangeInclusive<u32>,
+/// }
+///
+/// let spans = Spans::default();
+/// assert_eq!(spans.range, CopyRange { start: 0, end: 0 });
+/// assert!(spans.range.is_empty());
+/// assert_eq!(spans.from, CopyRangeFrom { start: 0 });
+/// assert_eq!(spans.inclusive, CopyRangeInclusive { start: 0, end: 0 });
+/// assert!(spans.inclusive.contains(&0));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CopyRangeInclusive<Idx> {
+    pub start: Idx,
+    pub end: Idx,
+}
 
 impl<Idx: core::fmt::Debug> core::fmt::Debug for CopyRangeInclusive<Idx> {
     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if fmt.alternate() {
+            return fmt
+                .debug_struct("CopyRangeInclusive")
+                .field("start", &self.start)
+                .field("end", &self.end)
+                .finish();
+        }
         self.start.fmt(fmt)?;
         write!(fmt, "..=")?;
         self.end.fmt(fmt)?;
@@ -178,177 +1415,3340 @@ impl<Idx: core::fmt::Debug> core::fmt::Debug for CopyRangeInclusive<Idx> {
     }
 }
 
-impl<Idx> CopyRangeInclusive<Idx> {
-    /// Returns `true` if `item` is contained in the range.
-    ///
-    /// See [`RangeInclusive::contains`][core::ops::RangeInclusive::contains].
-    pub fn contains<U>(&self, item: &U) -> bool
-    where
-        Idx: PartialOrd<U>,
-        U: ?Sized + PartialOrd<Idx>,
-    {
-        <Self as RangeBounds<Idx>>::contains(self, item)
-    }
+impl<Idx> CopyRangeInclusive<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// See [`RangeInclusive::contains`][core::ops::RangeInclusive::contains].
+    ///
+    /// As with [`CopyRange::contains`], NaN is never contained, and a range
+    /// with a NaN endpoint contains nothing:
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let nan = f64::NAN;
+    /// assert!(
+    ///     !CopyRangeInclusive { start: f64::NEG_INFINITY, end: f64::INFINITY }
+    ///         .contains(&nan)
+    /// );
+    /// for range in [
+    ///     CopyRangeInclusive { start: nan, end: 1.0 },
+    ///     CopyRangeInclusive { start: 0.0, end: nan },
+    ///     CopyRangeInclusive { start: nan, end: nan },
+    /// ] {
+    ///     for item in [f64::NEG_INFINITY, -1.0, 0.0, 0.5, 1.0, f64::INFINITY, nan]
+    ///     {
+    ///         assert!(!range.contains(&item));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Ranges of integers also have `contains_branchless`, which gives the
+    /// same results.
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        <Self as RangeBounds<Idx>>::contains(self, item)
+    }
+
+    /// Returns `true` if every item in `items` is contained in the range.
+    ///
+    /// This stops at the first item which is not contained, and returns
+    /// `true` if `items` is empty.
+    pub fn contains_all<I>(&self, items: I) -> bool
+    where
+        I: IntoIterator<Item = Idx>,
+        Idx: PartialOrd,
+    {
+        items.into_iter().all(|item| self.contains(&item))
+    }
+
+    /// Returns `true` if the range contains no items.
+    ///
+    /// See [`RangeInclusive::is_empty`][core::ops::RangeInclusive::is_empty].
+    /// Like [`CopyRange::is_empty_total`], this is `!(start <= end)`, so the
+    /// range is empty if either endpoint is incomparable, e.g. NaN.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// assert!(!CopyRangeInclusive { start: 1.0, end: 1.0 }.is_empty());
+    /// assert!(CopyRangeInclusive { start: f64::NAN, end: 1.0 }.is_empty());
+    /// assert!(CopyRangeInclusive { start: 0.0, end: f64::NAN }.is_empty());
+    /// assert!(CopyRangeInclusive { start: f64::NAN, end: f64::NAN }.is_empty());
+    /// ```
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    pub fn is_empty(&self) -> bool
+    where
+        Idx: PartialOrd,
+    {
+        !(self.start <= self.end)
+    }
+
+    /// Returns the range with its endpoints swapped if `start > end`, so
+    /// that the result is never empty.
+    ///
+    /// Note that this changes which elements the range contains: a reversed
+    /// range is empty, but the swapped range is not. Only use this if reversed
+    /// ranges should be treated as if they were written the other way around,
+    /// e.g. for user input.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let range = CopyRangeInclusive { start: 5, end: 2 };
+    /// assert!(range.is_empty());
+    /// assert_eq!(range.normalized(), CopyRangeInclusive { start: 2, end: 5 });
+    /// assert!(!range.normalized().is_empty());
+    /// let range = CopyRangeInclusive { start: 2, end: 5 };
+    /// assert_eq!(range.normalized(), range);
+    /// ```
+    pub fn normalized(self) -> Self
+    where
+        Idx: Ord,
+    {
+        if self.start > self.end {
+            Self { start: self.end, end: self.start }
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` if `start <= end`, i.e. if the range is not reversed.
+    ///
+    /// For inclusive ranges, this is the same as `!self.is_empty()`.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// assert!(CopyRangeInclusive { start: 2, end: 5 }.is_well_formed());
+    /// assert!(CopyRangeInclusive { start: 3, end: 3 }.is_well_formed());
+    /// assert!(!CopyRangeInclusive { start: 5, end: 2 }.is_well_formed());
+    /// ```
+    pub fn is_well_formed(&self) -> bool
+    where
+        Idx: PartialOrd,
+    {
+        self.start <= self.end
+    }
+
+    /// Returns `true` if the range contains exactly one item, i.e. if
+    /// `start == end`.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// assert!(CopyRangeInclusive { start: 3, end: 3 }.is_singleton());
+    /// assert!(!CopyRangeInclusive { start: 3, end: 4 }.is_singleton());
+    /// // Empty (reversed) ranges are not singletons.
+    /// assert!(!CopyRangeInclusive { start: 4, end: 3 }.is_singleton());
+    /// ```
+    pub fn is_singleton(&self) -> bool
+    where
+        Idx: PartialEq,
+    {
+        self.start == self.end
+    }
+
+    /// Returns the only item in the range if it contains exactly one item, or
+    /// `None` otherwise.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// assert_eq!(
+    ///     CopyRangeInclusive { start: 3, end: 3 }.into_singleton(),
+    ///     Some(3)
+    /// );
+    /// assert_eq!(CopyRangeInclusive { start: 3, end: 4 }.into_singleton(), None);
+    /// assert_eq!(CopyRangeInclusive { start: 4, end: 3 }.into_singleton(), None);
+    /// ```
+    pub fn into_singleton(self) -> Option<Idx>
+    where
+        Idx: PartialEq,
+    {
+        if self.start == self.end { Some(self.start) } else { None }
+    }
+
+    /// Convert a [`RangeInclusive`] into a `CopyRangeInclusive`.
+    ///
+    /// Note: the value returned by this conversion is unspecified after the
+    /// `RangeInclusive` has been iterated to exhaustion.
+    ///
+    /// See also [`into_std`][Self::into_std] and, for integer ranges,
+    /// [`to_exclusive`](Self::to_exclusive).
+    pub fn from_std(range: RangeInclusive<Idx>) -> Self {
+        range.into()
+    }
+
+    /// Convert a `CopyRangeInclusive` into a [`RangeInclusive`].
+    ///
+    /// To convert an integer range into an exclusive range instead, use
+    /// [`to_exclusive`](Self::to_exclusive), which checks for overflow of
+    /// `end + 1`, or
+    /// [`to_exclusive_saturating`](Self::to_exclusive_saturating).
+    pub fn into_std(self) -> RangeInclusive<Idx> {
+        self.into()
+    }
+
+    /// Creates a range from its start and end.
+    ///
+    /// This is the same as `CopyRangeInclusive { start, end }`, and does not
+    /// check that `start <= end`.
+    pub fn from_parts(start: Idx, end: Idx) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates a range from any [`RangeBounds`], cloning its bounds and using
+    /// `fallback_start` and `fallback_end` in place of unbounded ones.
+    ///
+    /// An excluded bound is converted to an included one with
+    /// [`CopyStep`]. Returns `None` if that adjustment overflows, i.e. for
+    /// ranges starting after the maximum value or ending before the minimum
+    /// value, which cannot be represented as a `CopyRangeInclusive` of the
+    /// same type.
+    ///
+    /// This lets functions accept any `impl RangeBounds<Idx>` and normalize
+    /// it to a single `Copy` type:
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeInclusive};
+    /// fn normalize(
+    ///     range: impl core::ops::RangeBounds<u64>,
+    /// ) -> Option<CopyRangeInclusive<u64>> {
+    ///     CopyRangeInclusive::from_bounds(&range, 0, 99)
+    /// }
+    ///
+    /// let from = |start, end| Some(CopyRangeInclusive { start, end });
+    /// assert_eq!(normalize(2..5), from(2, 4));
+    /// assert_eq!(normalize(2..), from(2, 99));
+    /// assert_eq!(normalize(..5), from(0, 4));
+    /// assert_eq!(normalize(2..=5), from(2, 5));
+    /// assert_eq!(normalize(..=5), from(0, 5));
+    /// assert_eq!(normalize(..), from(0, 99));
+    /// assert_eq!(normalize(CopyRange { start: 2, end: 5 }), from(2, 4));
+    /// assert_eq!(normalize(CopyRangeInclusive { start: 2, end: 5 }), from(2, 5));
+    ///
+    /// // Nothing is before the minimum value.
+    /// assert_eq!(normalize(..0), None);
+    /// ```
+    pub fn from_bounds<R>(
+        range: &R,
+        fallback_start: Idx,
+        fallback_end: Idx,
+    ) -> Option<Self>
+    where
+        R: RangeBounds<Idx> + ?Sized,
+        Idx: CopyStep + Clone,
+    {
+        let start = match range.start_bound().cloned() {
+            Bound::Unbounded => Bound::Included(fallback_start),
+            start => start,
+        };
+        let end = match range.end_bound().cloned() {
+            Bound::Unbounded => Bound::Included(fallback_end),
+            end => end,
+        };
+        Self::try_from((start, end)).ok()
+    }
+
+    /// Returns the start and end of the range, like
+    /// [`RangeInclusive::into_inner`].
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let pairs: Vec<(u32, u32)> = vec![(1, 3), (10, 20)];
+    /// let ranges: Vec<CopyRangeInclusive<u32>> =
+    ///     pairs.into_iter().map(Into::into).collect();
+    /// assert_eq!(ranges[1], CopyRangeInclusive::from_parts(10, 20));
+    /// assert_eq!(ranges[0].into_parts(), (1, 3));
+    /// ```
+    pub fn into_parts(self) -> (Idx, Idx) {
+        (self.start, self.end)
+    }
+
+    /// Applies `f` to both endpoints of the range.
+    ///
+    /// If `f` is not monotonic, the returned range may be empty (reversed)
+    /// even if `self` is not.
+    pub fn map<U, F>(self, mut f: F) -> CopyRangeInclusive<U>
+    where
+        F: FnMut(Idx) -> U,
+    {
+        CopyRangeInclusive { start: f(self.start), end: f(self.end) }
+    }
+
+    /// Applies the fallible function `f` to both endpoints of the range,
+    /// returning the first error if either fails.
+    ///
+    /// If `f` is not monotonic, the returned range may be empty (reversed)
+    /// even if `self` is not.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let range = CopyRangeInclusive { start: 1u32, end: 3 };
+    /// let data = [10, 11, 12, 13];
+    /// let range = range.try_map(usize::try_from).unwrap();
+    /// assert_eq!(data[range], [11, 12, 13]);
+    /// ```
+    ///
+    /// Like [`CopyRange`], the other range types can also be converted with
+    /// `TryFrom` whenever the integer types can.
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<CopyRangeInclusive<U>, E>
+    where
+        F: FnMut(Idx) -> Result<U, E>,
+    {
+        Ok(CopyRangeInclusive { start: f(self.start)?, end: f(self.end)? })
+    }
+
+    /// Borrows both endpoints of the range.
+    ///
+    /// The returned range implements [`RangeBounds<Idx>`], and can be mapped
+    /// to e.g. a range of `&str` to use with `BTreeMap<String, _>::range`:
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// # use std::collections::BTreeMap;
+    /// let map = BTreeMap::from([
+    ///     ("apple".to_string(), 1),
+    ///     ("banana".to_string(), 2),
+    ///     ("cherry".to_string(), 3),
+    /// ]);
+    /// let range = CopyRangeInclusive {
+    ///     start: "b".to_string(),
+    ///     end: "cherry".to_string(),
+    /// };
+    /// let values: Vec<_> = map
+    ///     .range::<str, _>(range.as_ref().map(String::as_str))
+    ///     .map(|(_, &v)| v)
+    ///     .collect();
+    /// assert_eq!(values, [2, 3]);
+    /// ```
+    pub fn as_ref(&self) -> CopyRangeInclusive<&Idx> {
+        CopyRangeInclusive { start: &self.start, end: &self.end }
+    }
+
+    /// Borrows both endpoints of the range.
+    ///
+    /// This is the same as [`as_ref`][Self::as_ref], and is provided for
+    /// symmetry with [`each_mut`][Self::each_mut], like
+    /// [`<[T; N]>::each_ref`][array::each_ref].
+    pub fn each_ref(&self) -> CopyRangeInclusive<&Idx> {
+        self.as_ref()
+    }
+
+    /// Mutably borrows both endpoints of the range.
+    pub fn each_mut(&mut self) -> CopyRangeInclusive<&mut Idx> {
+        CopyRangeInclusive { start: &mut self.start, end: &mut self.end }
+    }
+
+    /// Returns a copy of the range with `start` replaced.
+    pub fn with_start(self, start: Idx) -> Self {
+        Self { start, ..self }
+    }
+
+    /// Returns a copy of the range with `end` replaced.
+    pub fn with_end(self, end: Idx) -> Self {
+        Self { end, ..self }
+    }
+
+    /// Discards the end of the range, returning the range `start..`.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRangeInclusive, CopyRangeFrom};
+    /// let range = CopyRangeInclusive { start: 3, end: 8 };
+    /// assert_eq!(range.to_from(), CopyRangeFrom { start: 3 });
+    /// assert_eq!(range.to_from(), 3..);
+    /// ```
+    pub fn to_from(self) -> CopyRangeFrom<Idx> {
+        CopyRangeFrom { start: self.start }
+    }
+
+    /// Applies `f` to the start of the range, leaving the rest unchanged.
+    pub fn map_start<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Idx) -> Idx,
+    {
+        Self { start: f(self.start), ..self }
+    }
+
+    /// Applies `f` to the end of the range, leaving the rest unchanged.
+    pub fn map_end<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Idx) -> Idx,
+    {
+        Self { end: f(self.end), ..self }
+    }
+
+    /// Returns the start and end bounds of the range.
+    ///
+    /// This is like [`RangeBounds::start_bound`] and
+    /// [`RangeBounds::end_bound`], but returns owned bounds.
+    pub fn to_bounds(&self) -> (Bound<Idx>, Bound<Idx>)
+    where
+        Idx: Copy,
+    {
+        (Bound::Included(self.start), Bound::Included(self.end))
+    }
+
+    /// Returns the exact length of the range.
+    pub fn len(&self) -> usize
+    where
+        core::ops::RangeInclusive<Idx>: ExactSizeIterator,
+        Self: Copy,
+    {
+        self.into_std().len()
+    }
+
+    /// Collects the elements of the range into an array, if the range has
+    /// exactly `N` elements.
+    ///
+    /// Returns `None` if the range has more or fewer than `N` elements.
+    pub fn try_collect_array<const N: usize>(self) -> Option<[Idx; N]>
+    where
+        RangeInclusive<Idx>: Iterator<Item = Idx>,
+    {
+        let mut iter = self.into_iter();
+        // `RangeInclusive`'s `size_hint` is exact whenever the length fits in a
+        // `usize`.
+        if iter.size_hint() != (N, Some(N)) {
+            return None;
+        }
+        Some(core::array::from_fn(|_| iter.next().unwrap()))
+    }
+
+    /// Returns an iterator over the elements of the range, without consuming
+    /// it.
+    ///
+    /// This is equivalent to `(*self).into_iter()`, since the range is `Copy`.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let range = CopyRangeInclusive { start: 'a', end: 'e' };
+    /// let vowels: String =
+    ///     range.iter().filter(|c| "aeiou".contains(*c)).collect();
+    /// assert_eq!(vowels, "ae");
+    /// assert_eq!(range.iter().count(), 5);
+    ///
+    /// // Each call starts afresh, even for a range covering the whole domain.
+    /// let all = CopyRangeInclusive { start: u8::MIN, end: u8::MAX };
+    /// assert_eq!(all.iter().count(), 256);
+    /// assert_eq!(all.iter().last(), Some(u8::MAX));
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn iter(&self) -> RangeInclusive<Idx>
+    where
+        Idx: Copy,
+        RangeInclusive<Idx>: Iterator<Item = Idx>,
+    {
+        (*self).into_iter()
+    }
+
+    /// Returns an iterator over the elements of the range, each paired with
+    /// its offset from `start`.
+    ///
+    /// This is equivalent to `self.into_iter().enumerate()`. An empty range
+    /// yields nothing.
+    pub fn indexed(self) -> core::iter::Enumerate<RangeInclusive<Idx>>
+    where
+        RangeInclusive<Idx>: Iterator<Item = Idx>,
+    {
+        self.into_iter().enumerate()
+    }
+
+    /// Returns the range of elements contained in both `self` and `other`, or
+    /// `None` if there are no such elements.
+    ///
+    /// Ranges which share only an endpoint overlap at that endpoint.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let a = CopyRangeInclusive { start: 0, end: 5 };
+    /// let b = CopyRangeInclusive { start: 5, end: 9 };
+    /// assert_eq!(a.intersect(&b), Some(CopyRangeInclusive { start: 5, end: 5 }));
+    ///
+    /// let c = CopyRangeInclusive { start: 6, end: 9 };
+    /// assert_eq!(a.intersect(&c), None);
+    ///
+    /// let full = CopyRangeInclusive { start: 0, end: u8::MAX };
+    /// let top = CopyRangeInclusive { start: 200, end: u8::MAX };
+    /// assert_eq!(full.intersect(&top), Some(top));
+    /// ```
+    pub fn intersect(&self, other: &CopyRangeInclusive<Idx>) -> Option<Self>
+    where
+        Idx: Ord + Copy,
+    {
+        let start = Ord::max(self.start, other.start);
+        let end = Ord::min(self.end, other.end);
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// Narrows `self` in place to the elements it has in common with
+    /// `other`, returning `true` if there are any.
+    ///
+    /// Afterwards, `self` is always `max(start, other.start)..=min(end,
+    /// other.end)`. If this returns `false`, that range is empty (reversed);
+    /// for integers, [`clamp_to`](Self::clamp_to) gives a canonical empty
+    /// range instead.
+    pub fn intersect_with(&mut self, other: &CopyRangeInclusive<Idx>) -> bool
+    where
+        Idx: Ord + Copy,
+    {
+        self.start = Ord::max(self.start, other.start);
+        self.end = Ord::min(self.end, other.end);
+        self.start <= self.end
+    }
+
+    /// Restricts `value` to be within the range, i.e. returns `start` if
+    /// `value < start`, `end` if `value > end`, and `value` otherwise.
+    ///
+    /// This only requires `PartialOrd`, so it works for floats; like
+    /// [`f64::clamp`], a NaN `value` is returned unchanged.
+    ///
+    /// In debug builds, this panics if the range is empty.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let unit = CopyRangeInclusive { start: 0.0, end: 1.0 };
+    /// assert_eq!(unit.clamp_value(0.25), 0.25);
+    /// assert_eq!(unit.clamp_value(-3.0), 0.0);
+    /// assert_eq!(unit.clamp_value(1.5), 1.0);
+    /// ```
+    pub fn clamp_value(&self, value: Idx) -> Idx
+    where
+        Idx: PartialOrd + Copy,
+    {
+        debug_assert!(!self.is_empty(), "cannot clamp to an empty range");
+        if value < self.start {
+            self.start
+        } else if value > self.end {
+            self.end
+        } else {
+            value
+        }
+    }
+
+    /// Returns the smallest range containing both `self` and `point`, by
+    /// lowering `start` or raising `end` to `point`.
+    ///
+    /// If the range is empty, this returns `point..=point`. Like
+    /// [`CopyRange::expand_to_include`], this takes and returns the range by
+    /// value, but since `end` is inclusive, it never needs to fail.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let range = CopyRangeInclusive { start: 'm', end: 'p' };
+    /// let range = range.expand_to_include('c');
+    /// assert_eq!(range, CopyRangeInclusive { start: 'c', end: 'p' });
+    /// assert_eq!(range.expand_to_include('n'), range);
+    /// assert_eq!(
+    ///     range.expand_to_include('z'),
+    ///     CopyRangeInclusive { start: 'c', end: 'z' }
+    /// );
+    ///
+    /// let empty = CopyRangeInclusive { start: 1u8, end: 0 };
+    /// let range = empty.expand_to_include(u8::MAX);
+    /// assert_eq!(range, CopyRangeInclusive { start: u8::MAX, end: u8::MAX });
+    /// assert_eq!(
+    ///     range.expand_to_include(0),
+    ///     CopyRangeInclusive { start: 0, end: u8::MAX }
+    /// );
+    /// ```
+    #[must_use = "this returns the expanded range without modifying `self`"]
+    pub fn expand_to_include(self, point: Idx) -> Self
+    where
+        Idx: Ord + Copy,
+    {
+        if self.is_empty() {
+            Self { start: point, end: point }
+        } else {
+            Self {
+                start: Ord::min(self.start, point),
+                end: Ord::max(self.end, point),
+            }
+        }
+    }
+
+    /// Returns the smallest range containing every item of `points`, i.e.
+    /// `min..=max`, or `None` if `points` is empty.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let pitches = [64u8, 60, 67, 72];
+    /// assert_eq!(
+    ///     CopyRangeInclusive::from_points(pitches),
+    ///     Some(CopyRangeInclusive { start: 60, end: 72 }),
+    /// );
+    /// assert_eq!(
+    ///     CopyRangeInclusive::from_points([u8::MAX, 0]),
+    ///     Some(CopyRangeInclusive { start: 0, end: u8::MAX }),
+    /// );
+    /// assert_eq!(CopyRangeInclusive::<char>::from_points([]), None);
+    /// ```
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Idx>,
+        Idx: Ord + Copy,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let range = Self { start: first, end: first };
+        Some(points.fold(range, Self::expand_to_include))
+    }
+
+    /// Returns `true` if `self` and `other` have any elements in common.
+    ///
+    /// Ranges which share only an endpoint overlap at that endpoint. Empty
+    /// ranges do not overlap anything.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let a = CopyRangeInclusive { start: 0, end: 4 };
+    /// assert!(a.overlaps(&CopyRangeInclusive { start: 4, end: 9 }));
+    /// assert!(a.overlaps(&CopyRangeInclusive { start: 1, end: 2 }));
+    /// assert!(!a.overlaps(&CopyRangeInclusive { start: 5, end: 9 }));
+    /// let high = CopyRangeInclusive { start: 250, end: u8::MAX };
+    /// assert!(
+    ///     high.overlaps(&CopyRangeInclusive { start: u8::MAX, end: u8::MAX })
+    /// );
+    /// assert!(!high.overlaps(&CopyRangeInclusive { start: 0, end: 249 }));
+    /// // Empty ranges do not overlap anything, not even themselves.
+    /// let empty = CopyRangeInclusive { start: 3, end: 2 };
+    /// assert!(!a.overlaps(&empty));
+    /// assert!(!empty.overlaps(&empty));
+    /// ```
+    pub fn overlaps(&self, other: &CopyRangeInclusive<Idx>) -> bool
+    where
+        Idx: PartialOrd,
+    {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.start <= other.end
+            && other.start <= self.end
+    }
+
+    /// Returns the smallest range containing all elements of `self` and
+    /// `other`, or `None` if they do not overlap.
+    ///
+    /// If either range is empty, the other is returned. For primitive
+    /// integers, [`union`](CopyRangeInclusive::<u8>::union) additionally
+    /// merges adjacent ranges such as `0..=4` and `5..=9`.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let a = CopyRangeInclusive { start: 0u8, end: 4 };
+    /// assert_eq!(
+    ///     a.union_overlapping(&CopyRangeInclusive { start: 4, end: 9 }),
+    ///     Some(CopyRangeInclusive { start: 0, end: 9 }),
+    /// );
+    /// // Adjacent ranges are not merged.
+    /// assert_eq!(
+    ///     a.union_overlapping(&CopyRangeInclusive { start: 5, end: 9 }),
+    ///     None
+    /// );
+    ///
+    /// let high = CopyRangeInclusive { start: 250, end: u8::MAX };
+    /// assert_eq!(
+    ///     high.union_overlapping(&CopyRangeInclusive { start: 0, end: 10 }),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     high.union_overlapping(&CopyRangeInclusive { start: 0, end: 250 }),
+    ///     Some(CopyRangeInclusive { start: 0, end: u8::MAX }),
+    /// );
+    /// ```
+    pub fn union_overlapping(&self, other: &Self) -> Option<Self>
+    where
+        Idx: Ord + Copy,
+    {
+        if self.is_empty() {
+            Some(*other)
+        } else if other.is_empty() {
+            Some(*self)
+        } else if self.overlaps(other) {
+            Some(Self {
+                start: Ord::min(self.start, other.start),
+                end: Ord::max(self.end, other.end),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if every element of `other` is contained in `self`.
+    ///
+    /// See [`CopyRange::encloses`] for how bounds are compared.
+    pub fn encloses<R>(&self, other: &R) -> bool
+    where
+        Idx: PartialOrd,
+        R: ?Sized + RangeBounds<Idx>,
+    {
+        range_encloses(self, other)
+    }
+
+    /// Returns `true` if `self` and `other` have any elements in common.
+    ///
+    /// See [`CopyRange::intersects`] for how bounds are compared.
+    pub fn intersects<R>(&self, other: &R) -> bool
+    where
+        Idx: PartialOrd,
+        R: ?Sized + RangeBounds<Idx>,
+    {
+        range_intersects(self, other)
+    }
+}
+
+/// Convert a [`RangeInclusive`] into a `CopyRangeInclusive`.
+///
+/// Note: the value returned by this conversion is unspecified after the
+/// `RangeInclusive` has been iterated to exhaustion.
+impl<Idx> From<RangeInclusive<Idx>> for CopyRangeInclusive<Idx> {
+    fn from(range: RangeInclusive<Idx>) -> Self {
+        let (start, end) = range.into_inner();
+        Self { start, end }
+    }
+}
+
+/// Convert a `CopyRangeInclusive` into a [`RangeInclusive`].
+impl<Idx> From<CopyRangeInclusive<Idx>> for RangeInclusive<Idx> {
+    fn from(value: CopyRangeInclusive<Idx>) -> Self {
+        value.start..=value.end
+    }
+}
+
+/// Convert a `(start, end)` tuple into a `CopyRangeInclusive`.
+///
+/// This does not check that `start <= end`.
+impl<Idx> From<(Idx, Idx)> for CopyRangeInclusive<Idx> {
+    fn from((start, end): (Idx, Idx)) -> Self {
+        CopyRangeInclusive { start, end }
+    }
+}
+
+/// Convert a `CopyRangeInclusive` into a `(start, end)` tuple.
+impl<Idx> From<CopyRangeInclusive<Idx>> for (Idx, Idx) {
+    fn from(value: CopyRangeInclusive<Idx>) -> Self {
+        (value.start, value.end)
+    }
+}
+
+/// Compare a `CopyRangeInclusive` with the [`start`](RangeInclusive::start)
+/// and [`end`](RangeInclusive::end) of a [`RangeInclusive`].
+///
+/// Note: like [`from_std`](CopyRangeInclusive::from_std), this does not
+/// take into account whether the `RangeInclusive` has been iterated to
+/// exhaustion, so the result of comparing with an exhausted
+/// `RangeInclusive` is unspecified.
+///
+/// ```rust
+/// # use copy_range::CopyRangeInclusive;
+/// assert_eq!(CopyRangeInclusive { start: 3, end: 7 }, 3..=7);
+/// assert_eq!(3..=7, CopyRangeInclusive { start: 3, end: 7 });
+/// assert_ne!(CopyRangeInclusive { start: 3, end: 7 }, 3..=6);
+///
+/// // An exhausted `RangeInclusive` is empty, but it may still compare equal
+/// // to a non-empty `CopyRangeInclusive`, namely its `from_std` conversion.
+/// let mut range = 3..=7;
+/// range.by_ref().for_each(drop);
+/// assert!(range.is_empty());
+/// let copy = CopyRangeInclusive::from_std(range.clone());
+/// assert_eq!(range, copy);
+/// ```
+impl<Idx: PartialEq> PartialEq<RangeInclusive<Idx>>
+    for CopyRangeInclusive<Idx>
+{
+    fn eq(&self, other: &RangeInclusive<Idx>) -> bool {
+        self.start == *other.start() && self.end == *other.end()
+    }
+}
+
+/// Compare the [`start`](RangeInclusive::start) and
+/// [`end`](RangeInclusive::end) of a [`RangeInclusive`] with a
+/// `CopyRangeInclusive`.
+///
+/// Like the reverse comparison, this does not take into account whether the
+/// `RangeInclusive` has been iterated to exhaustion.
+impl<Idx: PartialEq> PartialEq<CopyRangeInclusive<Idx>>
+    for RangeInclusive<Idx>
+{
+    fn eq(&self, other: &CopyRangeInclusive<Idx>) -> bool {
+        *self.start() == other.start && *self.end() == other.end
+    }
+}
+
+/// Order a `CopyRangeInclusive` and a [`RangeInclusive`] lexicographically
+/// by `start`, then `end`, like two `CopyRangeInclusive`s.
+///
+/// `RangeInclusive` itself does not implement `PartialOrd`, so this is an
+/// extension beyond `core`. Like the `PartialEq` impl, this uses the
+/// `RangeInclusive`'s [`start`](RangeInclusive::start) and
+/// [`end`](RangeInclusive::end), so it does not take into account whether
+/// it has been iterated to exhaustion.
+///
+/// ```rust
+/// # use copy_range::CopyRangeInclusive;
+/// assert!(CopyRangeInclusive { start: 1, end: 5 } < (2..=3));
+/// assert!((1..=3) < CopyRangeInclusive { start: 1, end: 5 });
+/// ```
+impl<Idx: PartialOrd> PartialOrd<RangeInclusive<Idx>>
+    for CopyRangeInclusive<Idx>
+{
+    fn partial_cmp(&self, other: &RangeInclusive<Idx>) -> Option<Ordering> {
+        (&self.start, &self.end).partial_cmp(&(other.start(), other.end()))
+    }
+}
+
+/// Order a [`RangeInclusive`] and a `CopyRangeInclusive` lexicographically
+/// by `start`, then `end`, like two `CopyRangeInclusive`s.
+impl<Idx: PartialOrd> PartialOrd<CopyRangeInclusive<Idx>>
+    for RangeInclusive<Idx>
+{
+    fn partial_cmp(&self, other: &CopyRangeInclusive<Idx>) -> Option<Ordering> {
+        (self.start(), self.end()).partial_cmp(&(&other.start, &other.end))
+    }
+}
+
+/// A range only bounded exclusively above. See [`core::ops::RangeTo`].
+///
+/// Unlike `RangeTo`, this is a type defined by this crate, so it can have the
+/// same inherent methods as the other range types in this crate.
+///
+/// Note: this used to be a type alias for `RangeTo`. Code that constructed it
+/// with `..end` syntax should use `CopyRangeTo { end }` or
+/// [`CopyRangeTo::from_std`] instead. The old alias is available as the
+/// deprecated [`CopyRangeToStd`] for one release.
+///
+/// ```rust
+/// # use copy_range::CopyRangeTo;
+/// let range = CopyRangeTo { end: 3 };
+/// assert_eq!(range, CopyRangeTo::from_std(..3));
+/// assert_eq!(range.into_std(), ..3);
+/// assert_eq!(format!("{range:?}"), "..3");
+/// assert!(range.contains(&2) && !range.contains(&3));
+/// assert_eq!([1, 2, 3, 4][range], [1, 2, 3]);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CopyRangeTo<Idx> {
+    pub end: Idx,
+}
+
+impl<Idx: core::fmt::Debug> core::fmt::Debug for CopyRangeTo<Idx> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if fmt.alternate() {
+            return fmt
+                .debug_struct("CopyRangeTo")
+                .field("end", &self.end)
+                .finish();
+        }
+        write!(fmt, "..")?;
+        self.end.fmt(fmt)?;
+        Ok(())
+    }
+}
+
+impl<Idx> CopyRangeTo<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// See [`RangeTo::contains`][core::ops::RangeTo::contains].
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        <Self as RangeBounds<Idx>>::contains(self, item)
+    }
+
+    /// Convert a [`RangeTo`] into a `CopyRangeTo`.
+    pub fn from_std(range: RangeTo<Idx>) -> Self {
+        range.into()
+    }
+
+    /// Convert a `CopyRangeTo` into a [`RangeTo`].
+    pub fn into_std(self) -> RangeTo<Idx> {
+        self.into()
+    }
+
+    /// Borrows the end of the range.
+    ///
+    /// The returned range implements [`RangeBounds<Idx>`].
+    pub fn as_ref(&self) -> CopyRangeTo<&Idx> {
+        CopyRangeTo { end: &self.end }
+    }
+
+    /// Borrows the end of the range.
+    ///
+    /// This is the same as [`as_ref`][Self::as_ref], and is provided for
+    /// symmetry with [`each_mut`][Self::each_mut], like
+    /// [`<[T; N]>::each_ref`][array::each_ref].
+    pub fn each_ref(&self) -> CopyRangeTo<&Idx> {
+        self.as_ref()
+    }
+
+    /// Mutably borrows the end of the range.
+    pub fn each_mut(&mut self) -> CopyRangeTo<&mut Idx> {
+        CopyRangeTo { end: &mut self.end }
+    }
+
+    /// Returns a copy of the range with `end` replaced.
+    pub fn with_end(self, end: Idx) -> Self {
+        Self { end }
+    }
+
+    /// Applies `f` to the end of the range, leaving the rest unchanged.
+    pub fn map_end<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Idx) -> Idx,
+    {
+        Self { end: f(self.end) }
+    }
+
+    /// Returns the start and end bounds of the range.
+    ///
+    /// This is like [`RangeBounds::start_bound`] and
+    /// [`RangeBounds::end_bound`], but returns owned bounds.
+    pub fn to_bounds(&self) -> (Bound<Idx>, Bound<Idx>)
+    where
+        Idx: Copy,
+    {
+        (Bound::Unbounded, Bound::Excluded(self.end))
+    }
+}
+
+/// Convert a [`RangeTo`] into a `CopyRangeTo`.
+impl<Idx> From<RangeTo<Idx>> for CopyRangeTo<Idx> {
+    fn from(RangeTo { end }: RangeTo<Idx>) -> Self {
+        Self { end }
+    }
+}
+
+/// Convert a `CopyRangeTo` into a [`RangeTo`].
+impl<Idx> From<CopyRangeTo<Idx>> for RangeTo<Idx> {
+    fn from(value: CopyRangeTo<Idx>) -> Self {
+        ..value.end
+    }
+}
+
+/// Compare a `CopyRangeTo` with a [`RangeTo`].
+impl<Idx: PartialEq> PartialEq<RangeTo<Idx>> for CopyRangeTo<Idx> {
+    fn eq(&self, other: &RangeTo<Idx>) -> bool {
+        self.end == other.end
+    }
+}
+
+/// Compare a [`RangeTo`] with a `CopyRangeTo`.
+impl<Idx: PartialEq> PartialEq<CopyRangeTo<Idx>> for RangeTo<Idx> {
+    fn eq(&self, other: &CopyRangeTo<Idx>) -> bool {
+        self.end == other.end
+    }
+}
+
+/// Order a `CopyRangeTo` and a [`RangeTo`] by `end`.
+///
+/// `RangeTo` itself does not implement `PartialOrd`, so this is an extension
+/// beyond `core`.
+impl<Idx: PartialOrd> PartialOrd<RangeTo<Idx>> for CopyRangeTo<Idx> {
+    fn partial_cmp(&self, other: &RangeTo<Idx>) -> Option<Ordering> {
+        self.end.partial_cmp(&other.end)
+    }
+}
+
+/// Order a [`RangeTo`] and a `CopyRangeTo` by `end`.
+impl<Idx: PartialOrd> PartialOrd<CopyRangeTo<Idx>> for RangeTo<Idx> {
+    fn partial_cmp(&self, other: &CopyRangeTo<Idx>) -> Option<Ordering> {
+        self.end.partial_cmp(&other.end)
+    }
+}
+
+/// A range only bounded inclusively above. See
+/// [`core::ops::RangeToInclusive`].
+///
+/// Unlike `RangeToInclusive`, this is a type defined by this crate, so it can
+/// have the same inherent methods as the other range types in this crate.
+///
+/// Note: this used to be a type alias for `RangeToInclusive`. Code that
+/// constructed it with `..=end` syntax should use `CopyRangeToInclusive { end
+/// }` or [`CopyRangeToInclusive::from_std`] instead.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CopyRangeToInclusive<Idx> {
+    pub end: Idx,
+}
+
+impl<Idx: core::fmt::Debug> core::fmt::Debug for CopyRangeToInclusive<Idx> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if fmt.alternate() {
+            return fmt
+                .debug_struct("CopyRangeToInclusive")
+                .field("end", &self.end)
+                .finish();
+        }
+        write!(fmt, "..=")?;
+        self.end.fmt(fmt)?;
+        Ok(())
+    }
+}
+
+impl<Idx> CopyRangeToInclusive<Idx> {
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// See [`RangeToInclusive::contains`][core::ops::RangeToInclusive::contains].
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        Idx: PartialOrd<U>,
+        U: ?Sized + PartialOrd<Idx>,
+    {
+        <Self as RangeBounds<Idx>>::contains(self, item)
+    }
+
+    /// Convert a [`RangeToInclusive`] into a `CopyRangeToInclusive`.
+    pub fn from_std(range: RangeToInclusive<Idx>) -> Self {
+        range.into()
+    }
+
+    /// Convert a `CopyRangeToInclusive` into a [`RangeToInclusive`].
+    pub fn into_std(self) -> RangeToInclusive<Idx> {
+        self.into()
+    }
+
+    /// Borrows the end of the range.
+    ///
+    /// The returned range implements [`RangeBounds<Idx>`].
+    pub fn as_ref(&self) -> CopyRangeToInclusive<&Idx> {
+        CopyRangeToInclusive { end: &self.end }
+    }
+
+    /// Borrows the end of the range.
+    ///
+    /// This is the same as [`as_ref`][Self::as_ref], and is provided for
+    /// symmetry with [`each_mut`][Self::each_mut], like
+    /// [`<[T; N]>::each_ref`][array::each_ref].
+    pub fn each_ref(&self) -> CopyRangeToInclusive<&Idx> {
+        self.as_ref()
+    }
+
+    /// Mutably borrows the end of the range.
+    pub fn each_mut(&mut self) -> CopyRangeToInclusive<&mut Idx> {
+        CopyRangeToInclusive { end: &mut self.end }
+    }
+
+    /// Returns a copy of the range with `end` replaced.
+    pub fn with_end(self, end: Idx) -> Self {
+        Self { end }
+    }
+
+    /// Applies `f` to the end of the range, leaving the rest unchanged.
+    pub fn map_end<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Idx) -> Idx,
+    {
+        Self { end: f(self.end) }
+    }
+
+    /// Returns the start and end bounds of the range.
+    ///
+    /// This is like [`RangeBounds::start_bound`] and
+    /// [`RangeBounds::end_bound`], but returns owned bounds.
+    pub fn to_bounds(&self) -> (Bound<Idx>, Bound<Idx>)
+    where
+        Idx: Copy,
+    {
+        (Bound::Unbounded, Bound::Included(self.end))
+    }
+}
+
+/// Convert a [`RangeToInclusive`] into a `CopyRangeToInclusive`.
+impl<Idx> From<RangeToInclusive<Idx>> for CopyRangeToInclusive<Idx> {
+    fn from(RangeToInclusive { end }: RangeToInclusive<Idx>) -> Self {
+        Self { end }
+    }
+}
+
+/// Convert a `CopyRangeToInclusive` into a [`RangeToInclusive`].
+impl<Idx> From<CopyRangeToInclusive<Idx>> for RangeToInclusive<Idx> {
+    fn from(value: CopyRangeToInclusive<Idx>) -> Self {
+        ..=value.end
+    }
+}
+
+/// Compare a `CopyRangeToInclusive` with a [`RangeToInclusive`].
+impl<Idx: PartialEq> PartialEq<RangeToInclusive<Idx>>
+    for CopyRangeToInclusive<Idx>
+{
+    fn eq(&self, other: &RangeToInclusive<Idx>) -> bool {
+        self.end == other.end
+    }
+}
+
+/// Compare a [`RangeToInclusive`] with a `CopyRangeToInclusive`.
+impl<Idx: PartialEq> PartialEq<CopyRangeToInclusive<Idx>>
+    for RangeToInclusive<Idx>
+{
+    fn eq(&self, other: &CopyRangeToInclusive<Idx>) -> bool {
+        self.end == other.end
+    }
+}
+
+/// Order a `CopyRangeToInclusive` and a [`RangeToInclusive`] by `end`.
+///
+/// `RangeToInclusive` itself does not implement `PartialOrd`, so this is an
+/// extension beyond `core`.
+impl<Idx: PartialOrd> PartialOrd<RangeToInclusive<Idx>>
+    for CopyRangeToInclusive<Idx>
+{
+    fn partial_cmp(&self, other: &RangeToInclusive<Idx>) -> Option<Ordering> {
+        self.end.partial_cmp(&other.end)
+    }
+}
+
+/// Order a [`RangeToInclusive`] and a `CopyRangeToInclusive` by `end`.
+impl<Idx: PartialOrd> PartialOrd<CopyRangeToInclusive<Idx>>
+    for RangeToInclusive<Idx>
+{
+    fn partial_cmp(
+        &self,
+        other: &CopyRangeToInclusive<Idx>,
+    ) -> Option<Ordering> {
+        self.end.partial_cmp(&other.end)
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for CopyRange<Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Excluded(&self.end)
+    }
+}
+
+impl<Idx: ?Sized> RangeBounds<Idx> for CopyRange<&Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Included(self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Excluded(self.end)
+    }
+}
+
+impl<Idx> IntoIterator for CopyRange<Idx>
+where
+    Range<Idx>: Iterator<Item = Idx>,
+{
+    type Item = Idx;
+
+    type IntoIter = Range<Idx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..self.end
+    }
+}
+
+/// Iterate over a borrowed `CopyRange`.
+///
+/// This copies the endpoints into a new iterator, rather than borrowing the
+/// range, so the items are owned `Idx`s and the range is unchanged.
+///
+/// ```rust
+/// # use copy_range::CopyRange;
+/// let range = CopyRange { start: 1, end: 4 };
+/// let mut items = vec![];
+/// for i in &range {
+///     items.push(i);
+/// }
+/// assert_eq!(items, [1, 2, 3]);
+///
+/// fn sum<C>(collection: &C) -> i32
+/// where
+///     for<'a> &'a C: IntoIterator<Item = i32>,
+/// {
+///     collection.into_iter().sum()
+/// }
+/// assert_eq!(sum(&range), 6);
+/// assert_eq!(sum(&CopyRange { start: 5, end: 5 }), 0);
+/// // The range is unchanged, and can be iterated again.
+/// assert_eq!(sum(&range), 6);
+/// ```
+impl<Idx: Copy> IntoIterator for &CopyRange<Idx>
+where
+    Range<Idx>: Iterator<Item = Idx>,
+{
+    type Item = Idx;
+
+    type IntoIter = Range<Idx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..self.end
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for CopyRangeFrom<Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Unbounded
+    }
+}
+
+impl<Idx: ?Sized> RangeBounds<Idx> for CopyRangeFrom<&Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Included(self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Unbounded
+    }
+}
+
+impl<Idx> IntoIterator for CopyRangeFrom<Idx>
+where
+    RangeFrom<Idx>: Iterator<Item = Idx>,
+{
+    type Item = Idx;
+
+    type IntoIter = RangeFrom<Idx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..
+    }
+}
+
+/// Iterate over a borrowed `CopyRangeFrom`.
+///
+/// This copies the start into a new iterator, rather than borrowing the
+/// range, so the items are owned `Idx`s and the range is unchanged.
+///
+/// ```rust
+/// # use copy_range::CopyRangeFrom;
+/// let range = CopyRangeFrom { start: 'x' };
+/// assert_eq!((&range).into_iter().take(3).collect::<String>(), "xyz");
+/// assert_eq!(range, CopyRangeFrom { start: 'x' });
+/// ```
+impl<Idx: Copy> IntoIterator for &CopyRangeFrom<Idx>
+where
+    RangeFrom<Idx>: Iterator<Item = Idx>,
+{
+    type Item = Idx;
+
+    type IntoIter = RangeFrom<Idx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for CopyRangeInclusive<Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Included(&self.end)
+    }
+}
+
+impl<Idx: ?Sized> RangeBounds<Idx> for CopyRangeInclusive<&Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Included(self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Included(self.end)
+    }
+}
+
+impl<Idx> IntoIterator for CopyRangeInclusive<Idx>
+where
+    RangeInclusive<Idx>: Iterator<Item = Idx>,
+{
+    type Item = Idx;
+
+    type IntoIter = RangeInclusive<Idx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..=self.end
+    }
+}
+
+/// Iterate over a borrowed `CopyRangeInclusive`.
+///
+/// This copies the endpoints into a new `RangeInclusive` each time, so
+/// unlike iterating over a `RangeInclusive` by reference, the range is never
+/// exhausted, and iterating over it again yields the same items again.
+///
+/// ```rust
+/// # use copy_range::CopyRangeInclusive;
+/// let range = CopyRangeInclusive { start: 1, end: 3 };
+/// let mut items = vec![];
+/// for i in &range {
+///     items.push(i);
+/// }
+/// for i in &range {
+///     items.push(i);
+/// }
+/// assert_eq!(items, [1, 2, 3, 1, 2, 3]);
+///
+/// let all = CopyRangeInclusive { start: u8::MIN, end: u8::MAX };
+/// let first: Vec<u8> = (&all).into_iter().collect();
+/// let second: Vec<u8> = (&all).into_iter().collect();
+/// assert_eq!(first.len(), 256);
+/// assert_eq!(first, second);
+/// ```
+impl<Idx: Copy> IntoIterator for &CopyRangeInclusive<Idx>
+where
+    RangeInclusive<Idx>: Iterator<Item = Idx>,
+{
+    type Item = Idx;
+
+    type IntoIter = RangeInclusive<Idx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..=self.end
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for CopyRangeTo<Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Excluded(&self.end)
+    }
+}
+
+impl<Idx: ?Sized> RangeBounds<Idx> for CopyRangeTo<&Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Excluded(self.end)
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for CopyRangeToInclusive<Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Included(&self.end)
+    }
+}
+
+impl<Idx: ?Sized> RangeBounds<Idx> for CopyRangeToInclusive<&Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        Bound::Included(self.end)
+    }
+}
+
+/// Returns `true` if no element can lie between `start` and `end`.
+///
+/// Only the bounds themselves are compared, so e.g. `(Excluded(0),
+/// Excluded(1))` is not considered empty.
+#[allow(clippy::neg_cmp_op_on_partial_ord)]
+fn bounds_are_empty<T: ?Sized + PartialOrd>(
+    start: Bound<&T>,
+    end: Bound<&T>,
+) -> bool {
+    match (start, end) {
+        (Bound::Included(start), Bound::Included(end)) => !(start <= end),
+        (
+            Bound::Included(start) | Bound::Excluded(start),
+            Bound::Included(end) | Bound::Excluded(end),
+        ) => !(start < end),
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+    }
+}
+
+/// Returns `true` if every element above the start bound `inner` is also above
+/// the start bound `outer`.
+fn start_bound_encloses<T: ?Sized + PartialOrd>(
+    outer: Bound<&T>,
+    inner: Bound<&T>,
+) -> bool {
+    match (outer, inner) {
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (Bound::Excluded(outer), Bound::Included(inner)) => outer < inner,
+        (
+            Bound::Included(outer) | Bound::Excluded(outer),
+            Bound::Included(inner) | Bound::Excluded(inner),
+        ) => outer <= inner,
+    }
+}
+
+/// Returns `true` if every element below the end bound `inner` is also below
+/// the end bound `outer`.
+fn end_bound_encloses<T: ?Sized + PartialOrd>(
+    outer: Bound<&T>,
+    inner: Bound<&T>,
+) -> bool {
+    match (outer, inner) {
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (Bound::Excluded(outer), Bound::Included(inner)) => inner < outer,
+        (
+            Bound::Included(outer) | Bound::Excluded(outer),
+            Bound::Included(inner) | Bound::Excluded(inner),
+        ) => inner <= outer,
+    }
+}
+
+fn range_encloses<T, A, B>(outer: &A, inner: &B) -> bool
+where
+    T: ?Sized + PartialOrd,
+    A: ?Sized + RangeBounds<T>,
+    B: ?Sized + RangeBounds<T>,
+{
+    bounds_are_empty(inner.start_bound(), inner.end_bound())
+        || (start_bound_encloses(outer.start_bound(), inner.start_bound())
+            && end_bound_encloses(outer.end_bound(), inner.end_bound()))
+}
+
+fn range_intersects<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: ?Sized + PartialOrd,
+    A: ?Sized + RangeBounds<T>,
+    B: ?Sized + RangeBounds<T>,
+{
+    let start = if start_bound_encloses(a.start_bound(), b.start_bound()) {
+        b.start_bound()
+    } else {
+        a.start_bound()
+    };
+    let end = if end_bound_encloses(a.end_bound(), b.end_bound()) {
+        b.end_bound()
+    } else {
+        a.end_bound()
+    };
+    !bounds_are_empty(start, end)
+}
+
+macro_rules! impl_int {
+    ($($ty:ident)*) => {$(
+        impl CopyRange<$ty> {
+            /// Returns this range relative to `parent`, i.e. with
+            /// `parent.start` subtracted from both endpoints.
+            ///
+            /// Returns `None` if `self` is not contained in `parent` (i.e.
+            /// unless `parent.start <= self.start <= self.end <= parent.end`),
+            /// or, for signed integers, if the offsets do not fit in the
+            /// integer type (e.g. `100..120` relative to `-128..127` for
+            /// `i8`).
+            pub fn relative_to(self, parent: CopyRange<$ty>) -> Option<Self> {
+                if parent.start <= self.start
+                    && self.start <= self.end
+                    && self.end <= parent.end
+                {
+                    Some(Self {
+                        start: self.start.checked_sub(parent.start)?,
+                        end: self.end.checked_sub(parent.start)?,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the number of elements in the range, saturating at
+            /// `usize::MAX`.
+            ///
+            /// Returns `0` if the range is empty, including if `start > end`.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRange;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let all = CopyRange { start: T::MIN, end: T::MAX };
+            /// // Every value except `T::MAX`, e.g. 255 for `u8`, saturating at
+            /// // `usize::MAX` for `u128`.
+            /// let expected = 1usize.checked_shl(T::BITS).map_or(usize::MAX, |n| n - 1);
+            /// assert_eq!(all.saturating_len(), expected);
+            ///
+            /// assert_eq!(CopyRange::<T> { start: 3, end: 5 }.saturating_len(), 2);
+            /// assert_eq!(CopyRange { start: T::MAX, end: T::MAX }.saturating_len(), 0);
+            /// assert_eq!(CopyRange { start: T::MAX, end: T::MIN }.saturating_len(), 0);
+            /// ```
+            pub fn saturating_len(&self) -> usize {
+                if self.start < self.end {
+                    usize::try_from(self.end.abs_diff(self.start) as u128)
+                        .unwrap_or(usize::MAX)
+                } else {
+                    0
+                }
+            }
+
+            /// Converts this range to the equivalent inclusive range
+            /// `start..=end - 1`.
+            ///
+            /// Returns `None` if the range is empty (including if it is
+            /// reversed), since an empty inclusive range has no canonical
+            /// endpoints.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeInclusive};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRange::<T> { start: 2, end: 6 };
+            /// assert_eq!(range.to_inclusive(), Some(CopyRangeInclusive { start: 2, end: 5 }));
+            /// assert_eq!(CopyRange::<T> { start: 2, end: 2 }.to_inclusive(), None);
+            /// assert_eq!(CopyRange::<T> { start: 6, end: 2 }.to_inclusive(), None);
+            ///
+            /// // `to_inclusive` and `to_exclusive` round-trip exactly, for every
+            /// // range with endpoints near either end of the type.
+            /// let points = (T::MIN..=T::MIN + 2).chain(T::MAX - 2..=T::MAX);
+            /// for start in points.clone() {
+            ///     for end in points.clone() {
+            ///         let range = CopyRange { start, end };
+            ///         match range.to_inclusive() {
+            ///             Some(inclusive) => assert_eq!(inclusive.to_exclusive(), Some(range)),
+            ///             None => assert!(range.is_empty()),
+            ///         }
+            ///     }
+            /// }
+            /// ```
+            pub fn to_inclusive(self) -> Option<CopyRangeInclusive<$ty>> {
+                if self.start < self.end {
+                    Some(CopyRangeInclusive { start: self.start, end: self.end - 1 })
+                } else {
+                    None
+                }
+            }
+
+            /// Converts this range to the equivalent inclusive range
+            /// `start..=end - 1`, mapping empty ranges to an empty inclusive
+            /// range instead of failing.
+            ///
+            /// An empty range is mapped to `start..=start - 1`, or, if `start`
+            /// is the minimum value of the integer type, to `MIN + 1..=MIN`.
+            /// Only the emptiness of such ranges is preserved, not their
+            /// endpoints.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeInclusive};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRange::<T> { start: 2, end: 6 };
+            /// assert_eq!(
+            ///     range.to_inclusive_saturating(),
+            ///     CopyRangeInclusive { start: 2, end: 5 }
+            /// );
+            /// let range = CopyRange::<T> { start: 2, end: 2 };
+            /// assert_eq!(
+            ///     range.to_inclusive_saturating(),
+            ///     CopyRangeInclusive { start: 2, end: 1 }
+            /// );
+            /// let range = CopyRange::<T> { start: T::MIN, end: T::MIN };
+            /// assert_eq!(
+            ///     range.to_inclusive_saturating(),
+            ///     CopyRangeInclusive { start: T::MIN + 1, end: T::MIN }
+            /// );
+            /// assert!(range.to_inclusive_saturating().is_empty());
+            /// ```
+            pub fn to_inclusive_saturating(self) -> CopyRangeInclusive<$ty> {
+                if self.start < self.end {
+                    CopyRangeInclusive { start: self.start, end: self.end - 1 }
+                } else if self.start > $ty::MIN {
+                    CopyRangeInclusive { start: self.start, end: self.start - 1 }
+                } else {
+                    CopyRangeInclusive { start: $ty::MIN + 1, end: $ty::MIN }
+                }
+            }
+
+            /// Returns the first element of the range and the range of the
+            /// remaining elements, or `None` if the range is empty.
+            ///
+            /// E.g. `0..3` is split into `0` and `1..3`.
+            pub fn split_first(self) -> Option<($ty, Self)> {
+                if self.start < self.end {
+                    Some((self.start, Self { start: self.start + 1, end: self.end }))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the last element of the range and the range of the
+            /// remaining elements, or `None` if the range is empty.
+            ///
+            /// E.g. `0..3` is split into `2` and `0..2`.
+            pub fn split_last(self) -> Option<($ty, Self)> {
+                if self.start < self.end {
+                    Some((self.end - 1, Self { start: self.start, end: self.end - 1 }))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the smallest range containing both `self` and `point`,
+            /// by lowering `start` to `point` or raising `end` to `point + 1`.
+            ///
+            /// If the range is empty, this returns `point..point + 1`. Returns
+            /// `None` if `end` would need to be raised past the maximum value
+            /// of the integer type, i.e. if `point` is the maximum value.
+            ///
+            /// See also [`CopyRangeInclusive::expand_to_include`], which has
+            /// the same shape but never overflows, so returns the range
+            /// directly.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRange;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRange::<T> { start: 5, end: 10 };
+            /// assert_eq!(range.expand_to_include(2), Some(CopyRange { start: 2, end: 10 }));
+            /// assert_eq!(range.expand_to_include(7), Some(range));
+            /// assert_eq!(range.expand_to_include(10), Some(CopyRange { start: 5, end: 11 }));
+            /// assert_eq!(range.expand_to_include(T::MAX - 1), Some(CopyRange { start: 5, end: T::MAX }));
+            /// assert_eq!(range.expand_to_include(T::MAX), None);
+            ///
+            /// // An empty or reversed range becomes just `point`.
+            /// let empty = CopyRange::<T> { start: 5, end: 5 };
+            /// assert_eq!(empty.expand_to_include(20), Some(CopyRange { start: 20, end: 21 }));
+            /// let reversed = CopyRange { start: T::MAX, end: T::MIN };
+            /// assert_eq!(reversed.expand_to_include(T::MIN), Some(CopyRange { start: T::MIN, end: T::MIN + 1 }));
+            /// ```
+            #[must_use = "this returns the expanded range without modifying `self`"]
+            pub fn expand_to_include(self, point: $ty) -> Option<Self> {
+                let point_end = point.checked_add(1)?;
+                if self.start >= self.end {
+                    Some(Self { start: point, end: point_end })
+                } else {
+                    Some(Self {
+                        start: Ord::min(self.start, point),
+                        end: Ord::max(self.end, point_end),
+                    })
+                }
+            }
+
+            /// Returns an iterator over every element of the range, which
+            /// assumes that `start <= end`.
+            ///
+            /// For a reversed range, the returned iterator wraps around past
+            /// the maximum value of the integer type instead of yielding
+            /// nothing; see [`UncheckedIter`]. This is never undefined
+            /// behavior, but for ranges which may be reversed,
+            /// [`into_iter`](IntoIterator::into_iter) should be used instead.
+            pub fn unchecked_iter(self) -> UncheckedIter<$ty> {
+                UncheckedIter::<$ty>::new(self.start, self.end)
+            }
+
+            /// Returns the element halfway between `start` and `end`, i.e.
+            /// `start + (end - start) / 2`, the midpoint of `[start, end)`
+            /// rounded towards `start`.
+            ///
+            /// This does not overflow, even for e.g. `0..u64::MAX`. Returns
+            /// `start` if the range is empty, so that e.g. a binary search
+            /// can use this to find its insertion point.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRange;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRange { start: T::MAX - 3, end: T::MAX };
+            /// assert_eq!(range.midpoint(), T::MAX - 2);
+            /// let all = CopyRange { start: T::MIN, end: T::MAX };
+            /// // E.g. 127 for `u8` and -1 for `i8`.
+            /// assert_eq!(all.midpoint(), T::MIN / 2 + T::MAX / 2);
+            ///
+            /// // Empty and reversed ranges return `start`.
+            /// assert_eq!(CopyRange { start: T::MAX, end: T::MAX }.midpoint(), T::MAX);
+            /// assert_eq!(CopyRange { start: T::MAX, end: T::MIN }.midpoint(), T::MAX);
+            /// ```
+            pub fn midpoint(&self) -> $ty {
+                if self.start >= self.end {
+                    return self.start;
+                }
+                let half = self.end.abs_diff(self.start) / 2;
+                // `half < end - start`, so this does not actually wrap.
+                self.start.wrapping_add(half as $ty)
+            }
+
+            /// Returns the intersection of `self` and the inclusive range
+            /// `other`, as an exclusive range, or `None` if it is empty.
+            ///
+            /// This does not overflow, even if `other.end` is the maximum
+            /// value of the integer type.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeInclusive};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// // Every pair of ranges with endpoints near either end of the type,
+            /// // checked against membership of the values around those endpoints.
+            /// let points = (T::MIN..=T::MIN + 4).chain(T::MAX - 4..=T::MAX);
+            /// let values = (T::MIN..=T::MIN + 5).chain(T::MAX - 5..=T::MAX);
+            /// for start in points.clone() {
+            ///     for end in points.clone() {
+            ///         let exclusive = CopyRange { start, end };
+            ///         for other_start in points.clone() {
+            ///             for other_end in points.clone() {
+            ///                 let inclusive = CopyRangeInclusive { start: other_start, end: other_end };
+            ///                 let intersection = exclusive.intersect_inclusive(&inclusive);
+            ///                 for x in values.clone() {
+            ///                     assert_eq!(
+            ///                         intersection.is_some_and(|r| r.contains(&x)),
+            ///                         exclusive.contains(&x) && inclusive.contains(&x),
+            ///                     );
+            ///                 }
+            ///                 assert!(intersection.is_none_or(|r| !r.is_empty()));
+            ///             }
+            ///         }
+            ///     }
+            /// }
+            /// ```
+            pub fn intersect_inclusive(
+                &self,
+                other: &CopyRangeInclusive<$ty>,
+            ) -> Option<CopyRange<$ty>> {
+                let start = Ord::max(self.start, other.start);
+                let end = if other.end < self.end {
+                    // `other.end < self.end`, so this does not overflow.
+                    other.end + 1
+                } else {
+                    self.end
+                };
+                (start < end).then_some(CopyRange { start, end })
+            }
+
+            /// Returns the `n`th element of the range (counting from 0), or
+            /// `None` if the range has at most `n` elements.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRange;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// // Compare with the elements the range iterates over, for every range
+            /// // with endpoints near either end of the type.
+            /// let points = (T::MIN..=T::MIN + 4).chain(T::MAX - 4..=T::MAX);
+            /// for start in points.clone() {
+            ///     for end in points.clone() {
+            ///         let range = CopyRange { start, end };
+            ///         for n in (0..12).chain([usize::MAX]) {
+            ///             assert_eq!(range.get(n), range.into_iter().nth(n));
+            ///         }
+            ///         assert_eq!(range.first(), range.into_iter().next());
+            ///         assert_eq!(range.last(), range.into_iter().next_back());
+            ///     }
+            /// }
+            /// ```
+            pub fn get(&self, n: usize) -> Option<$ty> {
+                if self.start < self.end && (n as u128) < self.end.abs_diff(self.start) as u128 {
+                    // `n < end - start`, so this does not actually wrap.
+                    Some(self.start.wrapping_add(n as $ty))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the first element of the range, or `None` if it is
+            /// empty.
+            pub fn first(&self) -> Option<$ty> {
+                (self.start < self.end).then_some(self.start)
+            }
+
+            /// Returns the last element of the range, or `None` if it is
+            /// empty.
+            pub fn last(&self) -> Option<$ty> {
+                (self.start < self.end).then(|| self.end - 1)
+            }
+
+            /// Returns `true` if the range contains exactly one element, i.e.
+            /// if `end - 1 == start`.
+            ///
+            /// Empty ranges, including reversed ranges, are not singletons.
+            pub fn is_singleton(&self) -> bool {
+                self.end.checked_sub(1) == Some(self.start)
+            }
+
+            /// Adds `offset` to both endpoints of the range, or returns `None`
+            /// if either overflows.
+            pub fn checked_add_offset(self, offset: $ty) -> Option<Self> {
+                Some(Self {
+                    start: self.start.checked_add(offset)?,
+                    end: self.end.checked_add(offset)?,
+                })
+            }
+
+            /// Subtracts `offset` from both endpoints of the range, or returns
+            /// `None` if either overflows.
+            pub fn checked_sub_offset(self, offset: $ty) -> Option<Self> {
+                Some(Self {
+                    start: self.start.checked_sub(offset)?,
+                    end: self.end.checked_sub(offset)?,
+                })
+            }
+
+            /// Adds `offset` to both endpoints of the range, saturating each
+            /// at the bounds of the integer type.
+            ///
+            /// If an endpoint saturates, the returned range has fewer elements
+            /// than `self`.
+            pub fn saturating_add_offset(self, offset: $ty) -> Self {
+                Self {
+                    start: self.start.saturating_add(offset),
+                    end: self.end.saturating_add(offset),
+                }
+            }
+
+            /// Subtracts `offset` from both endpoints of the range, saturating
+            /// each at the bounds of the integer type.
+            ///
+            /// If an endpoint saturates, the returned range has fewer elements
+            /// than `self`.
+            pub fn saturating_sub_offset(self, offset: $ty) -> Self {
+                Self {
+                    start: self.start.saturating_sub(offset),
+                    end: self.end.saturating_sub(offset),
+                }
+            }
+
+            /// Returns an iterator over every `step`th element of the range,
+            /// starting with `start`.
+            ///
+            /// This yields the same elements as `self.into_iter().step_by(step)`,
+            /// but the returned iterator's type does not depend on the range
+            /// type.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `step` is 0, like [`Iterator::step_by`].
+            pub fn step_by(self, step: usize) -> CopyStepBy<$ty> {
+                if self.start < self.end {
+                    CopyStepBy::<$ty>::new_inclusive(self.start, self.end - 1, step)
+                } else {
+                    // Make an iterator that is already exhausted.
+                    CopyStepBy::<$ty>::new_inclusive(1, 0, step)
+                }
+            }
+
+            /// Converts this range to a range of `usize`, e.g. for indexing,
+            /// or returns an error if either endpoint does not fit in a
+            /// `usize`.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRange;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRange::<T> { start: 3, end: 10 };
+            /// assert_eq!(range.try_into_usize(), Ok(CopyRange { start: 3, end: 10 }));
+            ///
+            /// // A negative `start` does not fit.
+            /// let from_min = CopyRange { start: T::MIN, end: 0 };
+            /// assert_eq!(from_min.try_into_usize().is_ok(), T::MIN == 0);
+            /// // Nor does a value above `usize::MAX`, e.g. for `u128`, or for `u64` on
+            /// // 32-bit targets.
+            /// let to_max = CopyRange { start: 0, end: T::MAX };
+            /// assert_eq!(to_max.try_into_usize().is_ok(), T::MAX as u128 <= usize::MAX as u128);
+            /// ```
+            pub fn try_into_usize(
+                self,
+            ) -> Result<CopyRange<usize>, TryFromIntError> {
+                Ok(CopyRange {
+                    start: usize::try_from(self.start)?,
+                    end: usize::try_from(self.end)?,
+                })
+            }
+        }
+
+        impl CopyRangeInclusive<$ty> {
+            /// Converts this range to the equivalent exclusive range
+            /// `start..end + 1`.
+            ///
+            /// Returns `None` if `end + 1` would overflow, i.e. if `end` is
+            /// the maximum value of the integer type. Empty (reversed) ranges
+            /// are converted to the empty range `start..start`.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeInclusive};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRangeInclusive::<T> { start: 2, end: 5 };
+            /// assert_eq!(range.to_exclusive(), Some(CopyRange { start: 2, end: 6 }));
+            /// let range = CopyRangeInclusive::<T> { start: 0, end: T::MAX };
+            /// assert_eq!(range.to_exclusive(), None);
+            /// let range = CopyRangeInclusive::<T> { start: 5, end: 2 };
+            /// assert_eq!(range.to_exclusive(), Some(CopyRange { start: 5, end: 5 }));
+            /// ```
+            pub fn to_exclusive(self) -> Option<CopyRange<$ty>> {
+                if self.start > self.end {
+                    return Some(CopyRange { start: self.start, end: self.start });
+                }
+                let end = self.end.checked_add(1)?;
+                Some(CopyRange { start: self.start, end })
+            }
+
+            /// Returns the `n`th element of the range (counting from 0), or
+            /// `None` if the range has at most `n` elements.
+            ///
+            /// This works even if the range has more elements than fit in
+            /// its integer type, e.g. `get(255)` on `0..=u8::MAX` is
+            /// `Some(255)` and `get(256)` is `None`.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// // Compare with the elements the range iterates over, for every range
+            /// // with endpoints near either end of the type.
+            /// let points = (T::MIN..=T::MIN + 4).chain(T::MAX - 4..=T::MAX);
+            /// for start in points.clone() {
+            ///     for end in points.clone() {
+            ///         let range = CopyRangeInclusive { start, end };
+            ///         for n in (0..12).chain([usize::MAX]) {
+            ///             assert_eq!(range.get(n), range.into_iter().nth(n));
+            ///         }
+            ///         assert_eq!(range.first(), range.into_iter().next());
+            ///         assert_eq!(range.last(), range.into_iter().next_back());
+            ///     }
+            /// }
+            ///
+            /// let all = CopyRangeInclusive { start: T::MIN, end: T::MAX };
+            /// // E.g. `get(255)` and `get(256)` for `u8`.
+            /// if let Some(count) = all.checked_count() {
+            ///     assert_eq!(all.get(count - 1), Some(T::MAX));
+            ///     assert_eq!(all.get(count), None);
+            /// }
+            /// ```
+            pub fn get(&self, n: usize) -> Option<$ty> {
+                if self.start <= self.end && n as u128 <= self.end.abs_diff(self.start) as u128 {
+                    // `n <= end - start`, so this does not actually wrap.
+                    Some(self.start.wrapping_add(n as $ty))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the first element of the range, i.e. `start`, or
+            /// `None` if it is empty.
+            pub fn first(&self) -> Option<$ty> {
+                (self.start <= self.end).then_some(self.start)
+            }
+
+            /// Returns the last element of the range, i.e. `end`, or `None`
+            /// if it is empty.
+            pub fn last(&self) -> Option<$ty> {
+                (self.start <= self.end).then_some(self.end)
+            }
+
+            /// Converts this range to the exclusive range
+            /// `start..end + 1`, saturating `end + 1` at the maximum value of
+            /// the integer type.
+            ///
+            /// **This is lossy**: if `end` is the maximum value of the integer
+            /// type, the returned range does not contain it, and so has one
+            /// fewer element than `self`. Otherwise, this is the same as
+            /// [`to_exclusive`][Self::to_exclusive].
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeInclusive};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRangeInclusive::<T> { start: T::MAX - 5, end: T::MAX };
+            /// assert_eq!(
+            ///     range.to_exclusive_saturating(),
+            ///     CopyRange { start: T::MAX - 5, end: T::MAX }
+            /// );
+            /// assert!(!range.to_exclusive_saturating().contains(&T::MAX));
+            ///
+            /// // Otherwise, this agrees with `to_exclusive`, and round-trips, for
+            /// // every range with endpoints near either end of the type.
+            /// let points = (T::MIN..=T::MIN + 2).chain(T::MAX - 3..T::MAX);
+            /// for start in points.clone().chain([T::MAX]) {
+            ///     for end in points.clone() {
+            ///         let range = CopyRangeInclusive { start, end };
+            ///         let exclusive = range.to_exclusive_saturating();
+            ///         assert_eq!(Some(exclusive), range.to_exclusive());
+            ///         if !range.is_empty() {
+            ///             assert_eq!(exclusive.to_inclusive_saturating(), range);
+            ///         }
+            ///     }
+            /// }
+            /// ```
+            pub fn to_exclusive_saturating(self) -> CopyRange<$ty> {
+                if self.start > self.end {
+                    return CopyRange { start: self.start, end: self.start };
+                }
+                CopyRange { start: self.start, end: self.end.saturating_add(1) }
+            }
+
+            /// Adds `offset` to both endpoints of the range, or returns `None`
+            /// if either overflows.
+            ///
+            /// Since `end` is inclusive, this fails if `end + offset`
+            /// overflows even though `end + offset + 1` is never computed,
+            /// e.g. `0..=250u8` can be offset by `5` but not by `6`.
+            pub fn checked_add_offset(self, offset: $ty) -> Option<Self> {
+                Some(Self {
+                    start: self.start.checked_add(offset)?,
+                    end: self.end.checked_add(offset)?,
+                })
+            }
+
+            /// Subtracts `offset` from both endpoints of the range, or returns
+            /// `None` if either overflows.
+            pub fn checked_sub_offset(self, offset: $ty) -> Option<Self> {
+                Some(Self {
+                    start: self.start.checked_sub(offset)?,
+                    end: self.end.checked_sub(offset)?,
+                })
+            }
+
+            /// Adds `offset` to both endpoints of the range, saturating each
+            /// at the bounds of the integer type.
+            ///
+            /// If an endpoint saturates, the returned range has fewer elements
+            /// than `self`, e.g. offsetting `MAX - 20..=MAX - 10` by `15` gives
+            /// `MAX - 5..=MAX`, which has 6 elements rather than 11:
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRangeInclusive { start: T::MAX - 20, end: T::MAX - 10 };
+            /// // Only `end` saturates.
+            /// let offset = range.saturating_add_offset(15);
+            /// assert_eq!(offset, CopyRangeInclusive { start: T::MAX - 5, end: T::MAX });
+            /// assert_eq!(offset.count(), 6);
+            ///
+            /// // Neither endpoint saturates.
+            /// assert_eq!(
+            ///     range.saturating_add_offset(10),
+            ///     CopyRangeInclusive { start: T::MAX - 10, end: T::MAX },
+            /// );
+            /// // Both endpoints saturate, leaving a single element.
+            /// assert_eq!(
+            ///     range.saturating_add_offset(T::MAX),
+            ///     CopyRangeInclusive { start: T::MAX, end: T::MAX },
+            /// );
+            /// ```
+            pub fn saturating_add_offset(self, offset: $ty) -> Self {
+                Self {
+                    start: self.start.saturating_add(offset),
+                    end: self.end.saturating_add(offset),
+                }
+            }
+
+            /// Subtracts `offset` from both endpoints of the range, saturating
+            /// each at the bounds of the integer type.
+            ///
+            /// If an endpoint saturates, the returned range has fewer elements
+            /// than `self`.
+            pub fn saturating_sub_offset(self, offset: $ty) -> Self {
+                Self {
+                    start: self.start.saturating_sub(offset),
+                    end: self.end.saturating_sub(offset),
+                }
+            }
+
+            /// Returns an iterator over every `step`th element of the range,
+            /// starting with `start`.
+            ///
+            /// This yields the same elements as `self.into_iter().step_by(step)`,
+            /// but the returned iterator's type does not depend on the range
+            /// type.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `step` is 0, like [`Iterator::step_by`].
+            pub fn step_by(self, step: usize) -> CopyStepBy<$ty> {
+                CopyStepBy::<$ty>::new_inclusive(self.start, self.end, step)
+            }
+
+            /// Returns the first element of the range and the range of the
+            /// remaining elements, or `None` if the range is empty.
+            ///
+            /// The remaining range is `None` if the range has only one
+            /// element, so this never overflows, even if `start` is the
+            /// maximum value of the integer type.
+            pub fn split_first(self) -> Option<($ty, Option<Self>)> {
+                if self.start < self.end {
+                    Some((self.start, Some(Self { start: self.start + 1, end: self.end })))
+                } else if self.start == self.end {
+                    Some((self.start, None))
+                } else {
+                    None
+                }
+            }
 
-    /// Returns `true` if the range contains no items.
-    ///
-    /// See [`RangeInclusive::contains`][core::ops::RangeInclusive::contains].
-    pub fn is_empty(&self) -> bool
-    where
-        Idx: PartialOrd,
-    {
-        !(self.start <= self.end)
-    }
+            /// Returns the last element of the range and the range of the
+            /// remaining elements, or `None` if the range is empty.
+            ///
+            /// The remaining range is `None` if the range has only one
+            /// element, so this never overflows, even if `end` is the
+            /// minimum value of the integer type.
+            pub fn split_last(self) -> Option<($ty, Option<Self>)> {
+                if self.start < self.end {
+                    Some((self.end, Some(Self { start: self.start, end: self.end - 1 })))
+                } else if self.start == self.end {
+                    Some((self.end, None))
+                } else {
+                    None
+                }
+            }
 
-    /// Convert a [`RangeInclusive`] into a `CopyRangeInclusive`.
-    ///
-    /// Note: the value returned by this conversion is unspecified after the
-    /// `RangeInclusive` has been iterated to exhaustion.
-    pub fn from_std(range: RangeInclusive<Idx>) -> Self {
-        range.into()
-    }
+            /// Returns an iterator over consecutive, non-overlapping subranges
+            /// of the range, each with `size` elements, except for the last
+            /// which may have fewer.
+            ///
+            /// This does not overflow, even for ranges that end at the maximum
+            /// value of the integer type. An empty range yields no chunks.
+            pub fn chunks(self, size: NonZeroU64) -> InclusiveChunks<$ty> {
+                InclusiveChunks::<$ty>::new(self, size)
+            }
 
-    /// Convert a `CopyRangeInclusive` into a [`RangeInclusive`].
-    pub fn into_std(self) -> RangeInclusive<Idx> {
-        self.into()
-    }
+            /// Returns the number of elements in the range.
+            ///
+            /// Unlike [`len`](Self::len), this is exact even when the number
+            /// of elements does not fit in a `usize`, e.g. for
+            /// `i64::MIN..=i64::MAX`. The only range whose number of elements
+            /// does not fit in a `u128` is `0..=u128::MAX` (or
+            /// `i128::MIN..=i128::MAX`), for which this saturates to
+            /// `u128::MAX`.
+            ///
+            /// Returns `0` if the range is empty.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// // Every value of the integer type, e.g. 256 for `u8`.
+            /// let all = CopyRangeInclusive { start: T::MIN, end: T::MAX };
+            /// let expected = 1u128.checked_shl(T::BITS).unwrap_or(u128::MAX);
+            /// assert_eq!(all.count(), expected);
+            ///
+            /// assert_eq!(CopyRangeInclusive { start: T::MAX, end: T::MAX }.count(), 1);
+            /// assert_eq!(CopyRangeInclusive { start: T::MAX, end: T::MIN }.count(), 0);
+            /// assert_eq!(CopyRangeInclusive::<T> { start: 1, end: 0 }.count(), 0);
+            /// ```
+            pub fn count(&self) -> u128 {
+                if self.is_empty() {
+                    0
+                } else {
+                    (self.end.abs_diff(self.start) as u128).saturating_add(1)
+                }
+            }
 
-    /// Returns the exact length of the range.
-    pub fn len(&self) -> usize
-    where
-        core::ops::RangeInclusive<Idx>: ExactSizeIterator,
-        Self: Copy,
-    {
-        self.into_std().len()
-    }
+            /// Returns the number of elements in the range, or `None` if it
+            /// does not fit in a `usize`.
+            ///
+            /// Returns `Some(0)` if the range is empty.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let all = CopyRangeInclusive { start: T::MIN, end: T::MAX };
+            /// // `None` if there are more than `usize::MAX` values, e.g. for
+            /// // `i64::MIN..=i64::MAX` on 64-bit targets.
+            /// assert_eq!(all.checked_count(), 1usize.checked_shl(T::BITS));
+            ///
+            /// assert_eq!(CopyRangeInclusive { start: T::MAX, end: T::MIN }.checked_count(), Some(0));
+            /// ```
+            pub fn checked_count(&self) -> Option<usize> {
+                usize::try_from(self.count()).ok()
+            }
+
+            /// Returns the number of elements in the range, saturating at
+            /// `usize::MAX`.
+            ///
+            /// Returns `0` if the range is empty, i.e. if `start > end`.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let all = CopyRangeInclusive { start: T::MIN, end: T::MAX };
+            /// let expected = 1usize.checked_shl(T::BITS).unwrap_or(usize::MAX);
+            /// assert_eq!(all.saturating_len(), expected);
+            ///
+            /// assert_eq!(CopyRangeInclusive { start: T::MAX, end: T::MAX }.saturating_len(), 1);
+            /// assert_eq!(CopyRangeInclusive { start: T::MAX, end: T::MIN }.saturating_len(), 0);
+            /// ```
+            pub fn saturating_len(&self) -> usize {
+                self.checked_count().unwrap_or(usize::MAX)
+            }
+
+            /// Returns `true` if every element of `other` is contained in
+            /// `self`.
+            ///
+            /// Unlike [`encloses`](Self::encloses), this takes into account
+            /// that there are no integers between consecutive integers, so
+            /// e.g. `1..=5` contains `(Excluded(0), Excluded(6))`, and `..` is
+            /// contained in a range covering all values of the integer type.
+            /// This does not overflow, even when the bounds of `other` are at
+            /// the extremes of the integer type. An empty `other` is contained
+            /// in any range.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeInclusive};
+            /// use std::ops::Bound::{Excluded, Included, Unbounded};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let all = CopyRangeInclusive { start: T::MIN, end: T::MAX };
+            /// assert!(all.contains_range(&(..)));
+            /// assert!(all.contains_range(&(..=T::MAX)));
+            /// assert!(all.contains_range(&(T::MIN..)));
+            ///
+            /// // A window ending at the maximum value, which an exclusive range cannot
+            /// // express.
+            /// let high = CopyRangeInclusive { start: T::MAX - 15, end: T::MAX };
+            /// assert!(high.contains_range(&(T::MAX - 15..)));
+            /// assert!(high.contains_range(&(Excluded(T::MAX - 16), Unbounded)));
+            /// assert!(!high.contains_range(&(T::MAX - 16..)));
+            /// assert!(!high.contains_range(&(..=T::MAX)));
+            /// assert!(!high.contains_range(&(..)));
+            ///
+            /// let low = CopyRangeInclusive { start: T::MIN, end: T::MIN + 15 };
+            /// assert!(low.contains_range(&(..=T::MIN + 15)));
+            /// assert!(low.contains_range(&(..T::MIN + 16)));
+            /// assert!(!low.contains_range(&(..=T::MIN + 16)));
+            /// assert!(!low.contains_range(&(T::MIN..)));
+            ///
+            /// let small = CopyRangeInclusive::<T> { start: 1, end: 5 };
+            /// assert!(small.contains_range(&(Excluded(0), Excluded(6))));
+            /// assert!(!small.contains_range(&(Excluded(0), Included(6))));
+            /// assert!(small.contains_range(&CopyRange { start: 1, end: 6 }));
+            /// assert!(!small.contains_range(&CopyRange { start: 0, end: 5 }));
+            /// assert!(small.contains_range(&CopyRangeInclusive { start: 2, end: 5 }));
+            /// assert!(!small.contains_range(&CopyRangeInclusive { start: 2, end: 6 }));
+            /// // Empty ranges, including ones whose bounds leave no room for any
+            /// // elements, are contained in any range.
+            /// assert!(small.contains_range(&(Excluded(T::MAX), Unbounded)));
+            /// assert!(small.contains_range(&(..T::MIN)));
+            /// assert!(small.contains_range(&CopyRange { start: T::MAX, end: T::MAX }));
+            /// assert!(small.contains_range(&CopyRangeInclusive { start: T::MAX, end: T::MIN }));
+            /// ```
+            pub fn contains_range<R>(&self, other: &R) -> bool
+            where
+                R: ?Sized + RangeBounds<$ty>,
+            {
+                match Self::from_int_bounds(other) {
+                    Some(other) => {
+                        other.is_empty()
+                            || (self.start <= other.start && other.end <= self.end)
+                    }
+                    None => true,
+                }
+            }
+
+            /// Returns `true` if every element of the exclusive range `other`
+            /// is contained in `self`.
+            ///
+            /// This compares the bounds directly rather than converting
+            /// either range, so it works even if `self.end` is the maximum
+            /// value of the integer type. An empty `other` is contained in
+            /// any range.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeInclusive};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// // Every pair of ranges with endpoints near either end of the type,
+            /// // checked against membership of the values around those endpoints.
+            /// let points = (T::MIN..=T::MIN + 4).chain(T::MAX - 4..=T::MAX);
+            /// let values = (T::MIN..=T::MIN + 5).chain(T::MAX - 5..=T::MAX);
+            /// for start in points.clone() {
+            ///     for end in points.clone() {
+            ///         let exclusive = CopyRange { start, end };
+            ///         for other_start in points.clone() {
+            ///             for other_end in points.clone() {
+            ///                 let inclusive = CopyRangeInclusive { start: other_start, end: other_end };
+            ///                 let expected = values
+            ///                     .clone()
+            ///                     .filter(|x| exclusive.contains(x))
+            ///                     .all(|x| inclusive.contains(&x));
+            ///                 assert_eq!(inclusive.contains_exclusive_range(&exclusive), expected);
+            ///             }
+            ///         }
+            ///     }
+            /// }
+            /// ```
+            pub fn contains_exclusive_range(&self, other: &CopyRange<$ty>) -> bool {
+                // `other.end > other.start`, so `other.end - 1` does not
+                // overflow.
+                other.is_empty() || (self.start <= other.start && other.end - 1 <= self.end)
+            }
+
+            /// Returns the smallest range containing all elements of `self`
+            /// and `other`, or `None` if they neither overlap nor are
+            /// adjacent.
+            ///
+            /// Ranges are adjacent if one starts immediately after the other
+            /// ends, e.g. `0..=4` and `5..=9`. This does not overflow when
+            /// either range ends at the maximum value of the integer type.
+            ///
+            /// If either range is empty, the other is returned.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let a = CopyRangeInclusive::<T> { start: 0, end: 4 };
+            /// let b = CopyRangeInclusive { start: 5, end: 9 };
+            /// assert_eq!(a.union(&b), Some(CopyRangeInclusive { start: 0, end: 9 }));
+            /// assert_eq!(b.union(&a), Some(CopyRangeInclusive { start: 0, end: 9 }));
+            /// assert_eq!(a.union(&CopyRangeInclusive { start: 6, end: 9 }), None);
+            ///
+            /// let high = CopyRangeInclusive { start: T::MAX - 5, end: T::MAX };
+            /// assert_eq!(high.union(&CopyRangeInclusive { start: 0, end: 10 }), None);
+            /// assert_eq!(
+            ///     high.union(&CopyRangeInclusive { start: 0, end: T::MAX - 6 }),
+            ///     Some(CopyRangeInclusive { start: 0, end: T::MAX }),
+            /// );
+            ///
+            /// let empty = CopyRangeInclusive { start: T::MAX, end: T::MIN };
+            /// assert_eq!(empty.union(&high), Some(high));
+            /// assert_eq!(high.union(&empty), Some(high));
+            /// ```
+            pub fn union(&self, other: &Self) -> Option<Self> {
+                fn reaches(
+                    a: &CopyRangeInclusive<$ty>,
+                    b: &CopyRangeInclusive<$ty>,
+                ) -> bool {
+                    a.end.checked_add(1).is_none_or(|next| next >= b.start)
+                }
+                if self.is_empty() {
+                    Some(*other)
+                } else if other.is_empty() {
+                    Some(*self)
+                } else if reaches(self, other) && reaches(other, self) {
+                    Some(Self {
+                        start: Ord::min(self.start, other.start),
+                        end: Ord::max(self.end, other.end),
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the elements of `self` which are not contained in
+            /// `other`.
+            ///
+            /// The result consists of the part of `self` before `other` and
+            /// the part of `self` after `other`, either of which may be
+            /// absent. This does not overflow when `other` starts at the
+            /// minimum or ends at the maximum value of the integer type.
+            pub fn difference(&self, other: &Self) -> InclusiveDifference<$ty> {
+                if self.is_empty() || other.is_empty() {
+                    return InclusiveDifference {
+                        before: (!self.is_empty()).then_some(*self),
+                        after: None,
+                    };
+                }
+                // `other.start > self.start`, so `other.start - 1` does not
+                // overflow.
+                let before = (self.start < other.start).then(|| Self {
+                    start: self.start,
+                    end: Ord::min(self.end, other.start - 1),
+                });
+                // `other.end < self.end`, so `other.end + 1` does not
+                // overflow.
+                let after = (other.end < self.end).then(|| Self {
+                    start: Ord::max(self.start, other.end + 1),
+                    end: self.end,
+                });
+                InclusiveDifference { before, after }
+            }
+
+            /// Splits the range into the elements up to and including `mid`,
+            /// and the elements after `mid`.
+            ///
+            /// Either part is `None` if it would be empty, so if `mid` is
+            /// before `start` the whole range is in the second part, and if
+            /// `mid` is at or after `end` the whole range is in the first part.
+            /// The two parts never overlap, and together contain exactly the
+            /// elements of `self`. This does not overflow when `mid` is the
+            /// maximum value of the integer type.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let points = (T::MIN..=T::MIN + 4).chain(T::MAX - 4..=T::MAX);
+            /// for start in points.clone() {
+            ///     for end in points.clone() {
+            ///         let range = CopyRangeInclusive { start, end };
+            ///         for mid in points.clone() {
+            ///             let (before, after) = range.split_after(mid);
+            ///             for x in points.clone() {
+            ///                 let in_before = before.is_some_and(|r| r.contains(&x));
+            ///                 let in_after = after.is_some_and(|r| r.contains(&x));
+            ///                 assert_eq!(in_before, range.contains(&x) && x <= mid);
+            ///                 assert_eq!(in_after, range.contains(&x) && x > mid);
+            ///             }
+            ///             // The parts are non-empty and together cover `range` exactly.
+            ///             assert_eq!(before.map(|r| r.start), range.first().filter(|&s| s <= mid));
+            ///             assert_eq!(after.map(|r| r.end), range.last().filter(|&e| e > mid));
+            ///             if let (Some(before), Some(after)) = (before, after) {
+            ///                 assert_eq!(before.end.checked_add(1), Some(after.start));
+            ///             }
+            ///             assert!(before.into_iter().chain(after).all(|r| !r.is_empty()));
+            ///         }
+            ///     }
+            /// }
+            /// ```
+            pub fn split_after(&self, mid: $ty) -> (Option<Self>, Option<Self>) {
+                if self.is_empty() {
+                    return (None, None);
+                }
+                let before = (self.start <= mid).then(|| Self {
+                    start: self.start,
+                    end: Ord::min(mid, self.end),
+                });
+                // `mid < self.end`, so `mid + 1` does not overflow.
+                let after = (mid < self.end).then(|| Self {
+                    start: Ord::max(mid + 1, self.start),
+                    end: self.end,
+                });
+                (before, after)
+            }
+
+            /// Returns the element halfway between `start` and `end`,
+            /// rounding towards `start`, or `None` if the range is empty.
+            ///
+            /// This does not overflow, even for e.g. `i64::MIN..=i64::MAX`.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let all = CopyRangeInclusive { start: T::MIN, end: T::MAX };
+            /// // E.g. 127 for `u8` and -1 for `i8`.
+            /// assert_eq!(all.midpoint(), Some(T::MIN / 2 + T::MAX / 2));
+            ///
+            /// assert_eq!(CopyRangeInclusive::<T> { start: 2, end: 5 }.midpoint(), Some(3));
+            /// assert_eq!(CopyRangeInclusive { start: T::MAX, end: T::MAX }.midpoint(), Some(T::MAX));
+            /// assert_eq!(CopyRangeInclusive { start: T::MAX, end: T::MIN }.midpoint(), None);
+            /// ```
+            pub fn midpoint(&self) -> Option<$ty> {
+                if self.is_empty() {
+                    return None;
+                }
+                let half = self.end.abs_diff(self.start) / 2;
+                // `half` fits between `start` and `end`, so this does not
+                // actually wrap.
+                Some(self.start.wrapping_add(half as $ty))
+            }
+
+            /// Returns the first half of the range, from `start` to the
+            /// [`midpoint`](Self::midpoint) inclusive, or `None` if the range
+            /// is empty.
+            ///
+            /// Together with [`upper_half`](Self::upper_half), this splits the
+            /// range into two disjoint parts, as in a binary search.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let all = CopyRangeInclusive { start: T::MIN, end: T::MAX };
+            /// let lower = all.lower_half().unwrap();
+            /// let upper = all.upper_half().unwrap();
+            /// assert_eq!(lower.start, T::MIN);
+            /// assert_eq!(lower.end.checked_add(1), Some(upper.start));
+            /// assert_eq!(upper.end, T::MAX);
+            /// // The type has an even number of values, so the halves are the same size.
+            /// assert_eq!(lower.count(), upper.count());
+            ///
+            /// let single = CopyRangeInclusive { start: T::MIN, end: T::MIN };
+            /// assert_eq!(single.lower_half(), Some(single));
+            /// assert_eq!(CopyRangeInclusive { start: T::MAX, end: T::MIN }.lower_half(), None);
+            /// ```
+            pub fn lower_half(&self) -> Option<Self> {
+                let mid = self.midpoint()?;
+                Some(Self { start: self.start, end: mid })
+            }
+
+            /// Returns the second half of the range, from after the
+            /// [`midpoint`](Self::midpoint) to `end`, or `None` if the range
+            /// has fewer than two elements.
+            ///
+            /// Together with [`lower_half`](Self::lower_half), this splits the
+            /// range into two disjoint parts, as in a binary search.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let pair = CopyRangeInclusive { start: T::MAX - 1, end: T::MAX };
+            /// let single = CopyRangeInclusive { start: T::MAX, end: T::MAX };
+            /// assert_eq!(pair.upper_half(), Some(single));
+            /// // A single element is entirely in the lower half.
+            /// assert_eq!(single.upper_half(), None);
+            /// assert_eq!(CopyRangeInclusive { start: T::MAX, end: T::MIN }.upper_half(), None);
+            /// ```
+            pub fn upper_half(&self) -> Option<Self> {
+                let mid = self.midpoint()?;
+                // `mid < end`, so `mid + 1` does not overflow.
+                (mid < self.end).then(|| Self { start: mid + 1, end: self.end })
+            }
+
+            /// Converts this range to a range of `usize`, e.g. for indexing,
+            /// or returns an error if either endpoint does not fit in a
+            /// `usize`.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRangeInclusive::<T> { start: 3, end: 10 };
+            /// assert_eq!(range.try_into_usize(), Ok(CopyRangeInclusive { start: 3, end: 10 }));
+            ///
+            /// // A negative `start` does not fit.
+            /// let from_min = CopyRangeInclusive { start: T::MIN, end: 0 };
+            /// assert_eq!(from_min.try_into_usize().is_ok(), T::MIN == 0);
+            /// // Nor does a value above `usize::MAX`, e.g. for `u128`, or for `u64` on
+            /// // 32-bit targets.
+            /// let to_max = CopyRangeInclusive { start: 0, end: T::MAX };
+            /// assert_eq!(to_max.try_into_usize().is_ok(), T::MAX as u128 <= usize::MAX as u128);
+            /// ```
+            pub fn try_into_usize(
+                self,
+            ) -> Result<CopyRangeInclusive<usize>, TryFromIntError> {
+                Ok(CopyRangeInclusive {
+                    start: usize::try_from(self.start)?,
+                    end: usize::try_from(self.end)?,
+                })
+            }
+
+            /// Restricts the range to be within `outer`.
+            ///
+            /// This is like [`intersect`][Self::intersect], but returns an
+            /// empty range instead of `None` if the ranges do not intersect
+            /// (or either is empty). The empty range is always
+            /// `outer.end + 1..=outer.end`, the empty range just past `outer`,
+            /// or `MAX..=MAX - 1` if `outer.end` is the maximum value of the
+            /// integer type. It does not depend on `self`, so two disjoint
+            /// ranges clamped to the same `outer` compare equal.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let outer = CopyRangeInclusive::<T> { start: 10, end: 20 };
+            /// let range = CopyRangeInclusive::<T> { start: 5, end: 15 };
+            /// assert_eq!(range.clamp_to(&outer), CopyRangeInclusive { start: 10, end: 15 });
+            ///
+            /// let empty = CopyRangeInclusive::<T> { start: 21, end: 20 };
+            /// let above = CopyRangeInclusive::<T> { start: 25, end: 30 };
+            /// let below = CopyRangeInclusive::<T> { start: 0, end: 5 };
+            /// let reversed = CopyRangeInclusive::<T> { start: 15, end: 12 };
+            /// assert_eq!(above.clamp_to(&outer), empty);
+            /// assert_eq!(below.clamp_to(&outer), empty);
+            /// assert_eq!(reversed.clamp_to(&outer), empty);
+            /// assert!(empty.is_empty());
+            ///
+            /// let top = CopyRangeInclusive::<T> { start: 100, end: T::MAX };
+            /// assert_eq!(
+            ///     below.clamp_to(&top),
+            ///     CopyRangeInclusive { start: T::MAX, end: T::MAX - 1 }
+            /// );
+            /// assert_eq!(top.clamp_to(&top), top);
+            /// ```
+            pub fn clamp_to(&self, outer: &CopyRangeInclusive<$ty>) -> Self {
+                let start = Ord::max(self.start, outer.start);
+                let end = Ord::min(self.end, outer.end);
+                if start <= end {
+                    Self { start, end }
+                } else if outer.end < $ty::MAX {
+                    Self { start: outer.end + 1, end: outer.end }
+                } else {
+                    Self { start: $ty::MAX, end: $ty::MAX - 1 }
+                }
+            }
+
+            /// Converts the bounds of `range` into an inclusive range, or
+            /// returns `None` if an excluded bound leaves no room for any
+            /// elements (e.g. `Excluded(MAX)` as a start bound).
+            fn from_int_bounds<R>(range: &R) -> Option<Self>
+            where
+                R: ?Sized + RangeBounds<$ty>,
+            {
+                let start = match range.start_bound() {
+                    Bound::Included(&start) => start,
+                    Bound::Excluded(&start) => start.checked_add(1)?,
+                    Bound::Unbounded => $ty::MIN,
+                };
+                let end = match range.end_bound() {
+                    Bound::Included(&end) => end,
+                    Bound::Excluded(&end) => end.checked_sub(1)?,
+                    Bound::Unbounded => $ty::MAX,
+                };
+                Some(Self { start, end })
+            }
+        }
+
+        impl CopyRangeFrom<$ty> {
+            /// Returns an iterator over every element of the range, which
+            /// stops after the maximum value of the integer type instead of
+            /// overflowing.
+            ///
+            /// Iterating over a `RangeFrom` past the maximum value panics in
+            /// debug builds and wraps around in release builds.
+            pub fn iter_checked(self) -> CheckedFromIter<$ty> {
+                CheckedFromIter::<$ty>::new(self.start)
+            }
+
+            /// Returns the range of the first `n` elements of this range,
+            /// i.e. `start..start + n`.
+            ///
+            /// Returns `None` if `start + n` overflows, or, for signed
+            /// integers, if `n` is negative.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeFrom};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let from = CopyRangeFrom { start: T::MAX - 10 };
+            /// assert_eq!(from.take(10), Some(CopyRange { start: T::MAX - 10, end: T::MAX }));
+            /// assert_eq!(from.take(11), None);
+            /// assert_eq!(from.take(0), Some(CopyRange { start: T::MAX - 10, end: T::MAX - 10 }));
+            ///
+            /// let from = CopyRangeFrom { start: T::MAX };
+            /// assert_eq!(from.take(0), Some(CopyRange { start: T::MAX, end: T::MAX }));
+            /// assert_eq!(from.take(1), None);
+            /// ```
+            pub fn take(self, n: $ty) -> Option<CopyRange<$ty>> {
+                let end = self.start.checked_add(n)?;
+                (end >= self.start).then_some(CopyRange { start: self.start, end })
+            }
+
+            /// Returns the range of the first `n` elements of this range,
+            /// i.e. `start..start + n`, with `start + n` saturating at the
+            /// maximum value of the integer type.
+            ///
+            /// For signed integers, a negative `n` results in an empty range.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeFrom};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let from = CopyRangeFrom { start: T::MAX - 10 };
+            /// let to_max = CopyRange { start: T::MAX - 10, end: T::MAX };
+            /// assert_eq!(from.take_saturating(10), to_max);
+            /// assert_eq!(from.take_saturating(11), to_max);
+            /// assert_eq!(from.take_saturating(T::MAX), to_max);
+            /// assert_eq!(from.take_saturating(0), CopyRange { start: T::MAX - 10, end: T::MAX - 10 });
+            /// ```
+            pub fn take_saturating(self, n: $ty) -> CopyRange<$ty> {
+                let end = Ord::max(self.start.saturating_add(n), self.start);
+                CopyRange { start: self.start, end }
+            }
+
+            /// Returns the range of the first `n` elements of this range,
+            /// i.e. `start..start + n`, or `None` if `start + n` overflows.
+            ///
+            /// This is like [`take`][Self::take], but `n` is a `usize`, so it
+            /// may be larger than the maximum value of the integer type.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRange, CopyRangeFrom};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let from = CopyRangeFrom { start: T::MAX - 10 };
+            /// assert_eq!(from.take_usize(10), Some(CopyRange { start: T::MAX - 10, end: T::MAX }));
+            /// assert_eq!(from.take_usize(11), None);
+            /// assert_eq!(from.take_usize(usize::MAX), None);
+            /// assert_eq!(from.take_usize(0), Some(CopyRange { start: T::MAX - 10, end: T::MAX - 10 }));
+            /// ```
+            pub fn take_usize(self, n: usize) -> Option<CopyRange<$ty>> {
+                if n as u128 <= $ty::MAX.abs_diff(self.start) as u128 {
+                    // `n <= MAX - start`, so this does not actually wrap.
+                    Some(CopyRange { start: self.start, end: self.start.wrapping_add(n as $ty) })
+                } else {
+                    None
+                }
+            }
+
+            /// Moves the start of the range forward by `n`, i.e. returns
+            /// `start + n..`.
+            ///
+            /// Returns `None` if `start + n` overflows, or, for signed
+            /// integers, if `n` is negative.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeFrom;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let from = CopyRangeFrom { start: T::MAX - 10 };
+            /// assert_eq!(from.advance(0), Some(from));
+            /// assert_eq!(from.advance(10), Some(CopyRangeFrom { start: T::MAX }));
+            /// // Advancing past the maximum value fails rather than wrapping.
+            /// assert_eq!(from.advance(11), None);
+            /// assert_eq!(from.advance(T::MAX), None);
+            /// ```
+            pub fn advance(self, n: $ty) -> Option<Self> {
+                let start = self.start.checked_add(n)?;
+                (start >= self.start).then_some(Self { start })
+            }
+
+            /// Moves the start of the range forward by `n`, saturating at the
+            /// maximum value of the integer type.
+            ///
+            /// The result is always a valid range; once saturated, it
+            /// contains only the maximum value. For signed integers, a
+            /// negative `n` leaves the range unchanged.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeFrom;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let from = CopyRangeFrom { start: T::MAX - 10 };
+            /// assert_eq!(from.advance_saturating(0), from);
+            /// assert_eq!(from.advance_saturating(4), CopyRangeFrom { start: T::MAX - 6 });
+            /// assert_eq!(from.advance_saturating(11), CopyRangeFrom { start: T::MAX });
+            /// assert_eq!(from.advance_saturating(T::MAX), CopyRangeFrom { start: T::MAX });
+            /// ```
+            pub fn advance_saturating(self, n: $ty) -> Self {
+                Self { start: Ord::max(self.start.saturating_add(n), self.start) }
+            }
+
+            /// Moves the start of the range forward by `n` in place, like
+            /// [`advance`][Self::advance].
+            ///
+            /// Returns `false` and leaves the range unchanged if `advance`
+            /// would return `None`.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeFrom;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let mut cursor = CopyRangeFrom { start: T::MAX - 10 };
+            /// assert!(cursor.advance_in_place(4));
+            /// assert!(cursor.advance_in_place(6));
+            /// assert_eq!(cursor, CopyRangeFrom { start: T::MAX });
+            /// assert!(cursor.advance_in_place(0));
+            ///
+            /// // Advancing past the maximum value fails and leaves the range unchanged.
+            /// assert!(!cursor.advance_in_place(1));
+            /// assert_eq!(cursor, CopyRangeFrom { start: T::MAX });
+            /// ```
+            pub fn advance_in_place(&mut self, n: $ty) -> bool {
+                match self.advance(n) {
+                    Some(advanced) => {
+                        *self = advanced;
+                        true
+                    }
+                    None => false,
+                }
+            }
+
+            /// Converts this range to a range of `usize`, e.g. for indexing,
+            /// or returns an error if `start` does not fit in a `usize`.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeFrom;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRangeFrom::<T> { start: 3 };
+            /// assert_eq!(range.try_into_usize(), Ok(CopyRangeFrom { start: 3 }));
+            ///
+            /// // A negative `start` does not fit.
+            /// let from_min = CopyRangeFrom { start: T::MIN };
+            /// assert_eq!(from_min.try_into_usize().is_ok(), T::MIN == 0);
+            /// // Nor does a value above `usize::MAX`, e.g. for `u128`, or for `u64` on
+            /// // 32-bit targets.
+            /// let to_max = CopyRangeFrom { start: T::MAX };
+            /// assert_eq!(to_max.try_into_usize().is_ok(), T::MAX as u128 <= usize::MAX as u128);
+            /// ```
+            pub fn try_into_usize(
+                self,
+            ) -> Result<CopyRangeFrom<usize>, TryFromIntError> {
+                Ok(CopyRangeFrom { start: usize::try_from(self.start)? })
+            }
+
+            /// Restricts the range to be within `outer`, returning
+            /// `max(start, outer.start)..=outer.end`.
+            ///
+            /// If `start` is past `outer.end`, the result is the canonical
+            /// empty range `outer.end + 1..=outer.end` returned by
+            /// [`CopyRangeInclusive::clamp_to`], never a range which starts
+            /// more than one past its end. So if `outer` can be used to index
+            /// a slice, so can the result, whatever `start` is. Use
+            /// [`intersect_inclusive`][Self::intersect_inclusive] to get
+            /// `None` instead.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRangeFrom, CopyRangeInclusive};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let buf = [0u8; 16];
+            /// let outer = CopyRangeInclusive::<T> { start: 4, end: 11 };
+            /// for start in [T::MIN, 0, 4, 8, 11, 12, 100, T::MAX] {
+            ///     let range = CopyRangeFrom::<T> { start }.clamp_to_inclusive(&outer);
+            ///     if start <= outer.end {
+            ///         assert_eq!(range.end, outer.end);
+            ///         assert!(outer.contains(&range.start));
+            ///     } else {
+            ///         assert_eq!(range, CopyRangeInclusive { start: 12, end: 11 });
+            ///     }
+            ///     let range = range.try_into_usize().unwrap();
+            ///     let _ = &buf[range];
+            /// }
+            /// ```
+            pub fn clamp_to_inclusive(
+                &self,
+                outer: &CopyRangeInclusive<$ty>,
+            ) -> CopyRangeInclusive<$ty> {
+                CopyRangeInclusive { start: self.start, end: $ty::MAX }.clamp_to(outer)
+            }
+        }
+
+        impl CopyRangeTo<$ty> {
+            /// Converts this range to the equivalent inclusive range
+            /// `..=end - 1`.
+            ///
+            /// Returns `None` if `end - 1` would overflow, i.e. if `end` is
+            /// the minimum value of the integer type, since the range is then
+            /// empty and no `CopyRangeToInclusive` is.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRangeTo, CopyRangeToInclusive};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let to = CopyRangeTo { end: T::MAX };
+            /// assert_eq!(to.to_inclusive(), Some(CopyRangeToInclusive { end: T::MAX - 1 }));
+            /// let to = CopyRangeTo { end: T::MIN + 1 };
+            /// assert_eq!(to.to_inclusive(), Some(CopyRangeToInclusive { end: T::MIN }));
+            /// assert_eq!(CopyRangeTo { end: T::MIN }.to_inclusive(), None);
+            /// ```
+            pub fn to_inclusive(self) -> Option<CopyRangeToInclusive<$ty>> {
+                Some(CopyRangeToInclusive { end: self.end.checked_sub(1)? })
+            }
+        }
+
+        impl CopyRangeToInclusive<$ty> {
+            /// Converts this range to the equivalent exclusive range
+            /// `..end + 1`.
+            ///
+            /// Returns `None` if `end + 1` would overflow, i.e. if `end` is
+            /// the maximum value of the integer type.
+            ///
+            /// ```rust
+            /// # use copy_range::{CopyRangeTo, CopyRangeToInclusive};
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let to = CopyRangeToInclusive { end: T::MIN };
+            /// assert_eq!(to.to_exclusive(), Some(CopyRangeTo { end: T::MIN + 1 }));
+            /// let to = CopyRangeToInclusive { end: T::MAX - 1 };
+            /// assert_eq!(to.to_exclusive(), Some(CopyRangeTo { end: T::MAX }));
+            /// assert_eq!(CopyRangeToInclusive { end: T::MAX }.to_exclusive(), None);
+            /// ```
+            pub fn to_exclusive(self) -> Option<CopyRangeTo<$ty>> {
+                Some(CopyRangeTo { end: self.end.checked_add(1)? })
+            }
+        }
+    )*};
 }
 
-/// Convert a [`RangeInclusive`] into a `CopyRangeInclusive`.
-///
-/// Note: the value returned by this conversion is unspecified after the
-/// `RangeInclusive` has been iterated to exhaustion.
-impl<Idx> From<RangeInclusive<Idx>> for CopyRangeInclusive<Idx> {
-    fn from(range: RangeInclusive<Idx>) -> Self {
-        let (start, end) = range.into_inner();
-        Self { start, end }
-    }
+impl_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// Implements `grow` and `shrink`, which take the amount as the unsigned
+/// integer type of the same width, so that signed ranges can be grown by more
+/// than their maximum value. `$add` and `$sub` are the saturating methods for
+/// adding and subtracting the unsigned type.
+macro_rules! impl_grow_shrink {
+    ($($ty:ident => $uty:ident, $add:ident, $sub:ident;)*) => {$(
+        impl CopyRange<$ty> {
+            /// Moves `start` down and `end` up by `amount`, saturating at the
+            /// bounds of the integer type.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRange;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let low = CopyRange { start: T::MIN + 2, end: T::MIN + 5 };
+            /// assert_eq!(low.grow(3), CopyRange { start: T::MIN, end: T::MIN + 8 });
+            /// // `start` saturates at the minimum value.
+            /// assert_eq!(low.grow(4), CopyRange { start: T::MIN, end: T::MIN + 9 });
+            ///
+            /// let high = CopyRange { start: T::MAX - 5, end: T::MAX - 2 };
+            /// assert_eq!(high.grow(3), CopyRange { start: T::MAX - 8, end: T::MAX });
+            /// // `end` saturates at the maximum value.
+            /// assert_eq!(high.grow(4), CopyRange { start: T::MAX - 9, end: T::MAX });
+            /// ```
+            pub fn grow(self, amount: $uty) -> Self {
+                Self { start: self.start.$sub(amount), end: self.end.$add(amount) }
+            }
+
+            /// Moves `start` up and `end` down by `amount`.
+            ///
+            /// If `amount` is at least half the length of the range, this
+            /// returns the empty range at the
+            /// [`midpoint`](Self::midpoint) instead of a reversed range.
+            /// Empty ranges are returned unchanged.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRange;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRange::<T> { start: 10, end: 20 };
+            /// assert_eq!(range.shrink(3), CopyRange { start: 13, end: 17 });
+            /// // Shrinking by at least half the length gives the empty range at the
+            /// // midpoint, rather than a reversed range.
+            /// assert_eq!(range.shrink(5), CopyRange { start: 15, end: 15 });
+            /// assert_eq!(range.shrink(6), CopyRange { start: 15, end: 15 });
+            /// let odd = CopyRange::<T> { start: 10, end: 15 };
+            /// assert_eq!(odd.shrink(3), CopyRange { start: 12, end: 12 });
+            /// let high = CopyRange { start: T::MAX - 10, end: T::MAX };
+            /// assert_eq!(high.shrink(100), CopyRange { start: T::MAX - 5, end: T::MAX - 5 });
+            ///
+            /// // Empty ranges are unchanged.
+            /// let reversed = CopyRange { start: T::MAX, end: T::MIN };
+            /// assert_eq!(reversed.shrink(1), reversed);
+            /// ```
+            pub fn shrink(self, amount: $uty) -> Self {
+                if self.start >= self.end {
+                    return self;
+                }
+                let len = self.end.abs_diff(self.start);
+                if amount >= len.div_ceil(2) {
+                    let mid = self.midpoint();
+                    return Self { start: mid, end: mid };
+                }
+                // `2 * amount < end - start`, so these do not actually wrap.
+                Self {
+                    start: self.start.wrapping_add(amount as $ty),
+                    end: self.end.wrapping_sub(amount as $ty),
+                }
+            }
+        }
+
+        impl CopyRangeInclusive<$ty> {
+            /// Moves `start` down and `end` up by `amount`, saturating at the
+            /// bounds of the integer type.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let low = CopyRangeInclusive { start: T::MIN + 2, end: T::MIN + 5 };
+            /// assert_eq!(low.grow(3), CopyRangeInclusive { start: T::MIN, end: T::MIN + 8 });
+            /// // `start` saturates at the minimum value.
+            /// assert_eq!(low.grow(4), CopyRangeInclusive { start: T::MIN, end: T::MIN + 9 });
+            ///
+            /// let high = CopyRangeInclusive { start: T::MAX - 5, end: T::MAX - 2 };
+            /// assert_eq!(high.grow(3), CopyRangeInclusive { start: T::MAX - 8, end: T::MAX });
+            /// // `end` saturates at the maximum value.
+            /// assert_eq!(high.grow(4), CopyRangeInclusive { start: T::MAX - 9, end: T::MAX });
+            /// ```
+            pub fn grow(self, amount: $uty) -> Self {
+                Self { start: self.start.$sub(amount), end: self.end.$add(amount) }
+            }
+
+            /// Moves `start` up and `end` down by `amount`.
+            ///
+            /// If this would make the range empty, this instead returns the
+            /// range containing only the [`midpoint`](Self::midpoint), so a
+            /// non-empty range stays non-empty. Empty ranges are returned
+            /// unchanged.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRangeInclusive::<T> { start: 10, end: 20 };
+            /// assert_eq!(range.shrink(3), CopyRangeInclusive { start: 13, end: 17 });
+            /// // Shrinking past the midpoint leaves only the midpoint, rather than an
+            /// // empty range.
+            /// assert_eq!(range.shrink(5), CopyRangeInclusive { start: 15, end: 15 });
+            /// assert_eq!(range.shrink(6), CopyRangeInclusive { start: 15, end: 15 });
+            /// let odd = CopyRangeInclusive::<T> { start: 10, end: 15 };
+            /// assert_eq!(odd.shrink(3), CopyRangeInclusive { start: 12, end: 12 });
+            /// let high = CopyRangeInclusive { start: T::MAX - 10, end: T::MAX };
+            /// assert_eq!(high.shrink(100), CopyRangeInclusive { start: T::MAX - 5, end: T::MAX - 5 });
+            ///
+            /// // Empty ranges are unchanged.
+            /// let reversed = CopyRangeInclusive { start: T::MAX, end: T::MIN };
+            /// assert_eq!(reversed.shrink(1), reversed);
+            /// ```
+            pub fn shrink(self, amount: $uty) -> Self {
+                let Some(mid) = self.midpoint() else {
+                    return self;
+                };
+                if amount > self.end.abs_diff(self.start) / 2 {
+                    return Self { start: mid, end: mid };
+                }
+                // `2 * amount <= end - start`, so these do not actually wrap.
+                Self {
+                    start: self.start.wrapping_add(amount as $ty),
+                    end: self.end.wrapping_sub(amount as $ty),
+                }
+            }
+        }
+    )*};
 }
 
-/// Convert a `CopyRangeInclusive` into a [`RangeInclusive`].
-impl<Idx> From<CopyRangeInclusive<Idx>> for RangeInclusive<Idx> {
-    fn from(value: CopyRangeInclusive<Idx>) -> Self {
-        value.start..=value.end
-    }
+impl_grow_shrink! {
+    u8 => u8, saturating_add, saturating_sub;
+    u16 => u16, saturating_add, saturating_sub;
+    u32 => u32, saturating_add, saturating_sub;
+    u64 => u64, saturating_add, saturating_sub;
+    u128 => u128, saturating_add, saturating_sub;
+    usize => usize, saturating_add, saturating_sub;
+    i8 => u8, saturating_add_unsigned, saturating_sub_unsigned;
+    i16 => u16, saturating_add_unsigned, saturating_sub_unsigned;
+    i32 => u32, saturating_add_unsigned, saturating_sub_unsigned;
+    i64 => u64, saturating_add_unsigned, saturating_sub_unsigned;
+    i128 => u128, saturating_add_unsigned, saturating_sub_unsigned;
+    isize => usize, saturating_add_unsigned, saturating_sub_unsigned;
 }
 
-impl<Idx> RangeBounds<Idx> for CopyRange<Idx> {
-    fn start_bound(&self) -> Bound<&Idx> {
-        Bound::Included(&self.start)
-    }
+/// Implements `contains_branchless`, which compares the offsets from `start`
+/// as the unsigned integer type `$uty` of the same width, so that
+/// `start <= item < end` becomes a single comparison.
+macro_rules! impl_contains_branchless {
+    ($($ty:ident => $uty:ident;)*) => {$(
+        impl CopyRange<$ty> {
+            /// Returns `true` if `item` is contained in the range.
+            ///
+            /// This gives the same result as [`contains`](Self::contains),
+            /// but compiles to a fixed sequence of comparisons without
+            /// branches.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRange;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRange::<T> { start: 3, end: 7 };
+            /// assert!(range.contains_branchless(3));
+            /// assert!(range.contains_branchless(6));
+            /// assert!(!range.contains_branchless(7));
+            /// assert!(!range.contains_branchless(2));
+            /// assert!(!range.contains_branchless(T::MIN));
+            /// assert!(!range.contains_branchless(T::MAX));
+            ///
+            /// let full = CopyRange::<T> { start: T::MIN, end: T::MAX };
+            /// assert!(full.contains_branchless(T::MIN));
+            /// assert!(!full.contains_branchless(T::MAX));
+            ///
+            /// let reversed = CopyRange::<T> { start: 7, end: 3 };
+            /// assert!(!reversed.contains_branchless(5));
+            /// assert!(!reversed.contains_branchless(T::MIN));
+            /// ```
+            pub fn contains_branchless(&self, item: $ty) -> bool {
+                // If `item < start`, `offset` wraps around to a value of at
+                // least `len` (when `start <= end`), so one comparison checks
+                // both bounds. A reversed range would also wrap `len`, so it
+                // is checked separately, with `&` rather than `&&` to avoid a
+                // branch.
+                let offset = (item as $uty).wrapping_sub(self.start as $uty);
+                let len = (self.end as $uty).wrapping_sub(self.start as $uty);
+                (self.start <= self.end) & (offset < len)
+            }
+        }
 
-    fn end_bound(&self) -> Bound<&Idx> {
-        Bound::Excluded(&self.end)
-    }
+        impl CopyRangeInclusive<$ty> {
+            /// Returns `true` if `item` is contained in the range.
+            ///
+            /// This gives the same result as [`contains`](Self::contains),
+            /// but compiles to a fixed sequence of comparisons without
+            /// branches.
+            ///
+            /// ```rust
+            /// # use copy_range::CopyRangeInclusive;
+            #[doc = concat!("type T = ", stringify!($ty), ";")]
+            /// let range = CopyRangeInclusive::<T> { start: 3, end: 7 };
+            /// assert!(range.contains_branchless(3));
+            /// assert!(range.contains_branchless(7));
+            /// assert!(!range.contains_branchless(8));
+            /// assert!(!range.contains_branchless(2));
+            /// assert!(!range.contains_branchless(T::MIN));
+            ///
+            /// let full = CopyRangeInclusive::<T> { start: T::MIN, end: T::MAX };
+            /// assert!(full.contains_branchless(T::MIN));
+            /// assert!(full.contains_branchless(T::MAX));
+            ///
+            /// let reversed = CopyRangeInclusive::<T> { start: 7, end: 3 };
+            /// assert!(!reversed.contains_branchless(5));
+            /// assert!(!reversed.contains_branchless(T::MAX));
+            /// ```
+            pub fn contains_branchless(&self, item: $ty) -> bool {
+                // See `CopyRange::contains_branchless`.
+                let offset = (item as $uty).wrapping_sub(self.start as $uty);
+                let last = (self.end as $uty).wrapping_sub(self.start as $uty);
+                (self.start <= self.end) & (offset <= last)
+            }
+        }
+    )*};
 }
 
-impl<Idx> RangeBounds<Idx> for CopyRange<&Idx> {
-    fn start_bound(&self) -> Bound<&Idx> {
-        Bound::Included(self.start)
-    }
+impl_contains_branchless! {
+    u8 => u8;
+    u16 => u16;
+    u32 => u32;
+    u64 => u64;
+    u128 => u128;
+    usize => usize;
+    i8 => u8;
+    i16 => u16;
+    i32 => u32;
+    i64 => u64;
+    i128 => u128;
+    isize => usize;
+}
 
-    fn end_bound(&self) -> Bound<&Idx> {
-        Bound::Excluded(self.end)
-    }
+/// Implements `len` for the integer types whose `Range` implements
+/// `ExactSizeIterator`.
+///
+/// For unsigned types, the length of a non-empty range is computed with
+/// `wrapping_sub`, which cannot actually wrap since `start < end`, so the
+/// well-formed path is a single subtraction. Signed types defer to
+/// `Range::len`.
+macro_rules! impl_len {
+    (unsigned: $($uty:ident)*; signed: $($ity:ident)*;) => {
+        $(
+            impl CopyRange<$uty> {
+                /// Returns the exact length of the range.
+                ///
+                /// Returns `0` if the range is empty, including if
+                /// `start > end`. Otherwise, the length is computed with a
+                /// single subtraction, without an overflow check.
+                pub fn len(&self) -> usize {
+                    if self.is_empty() {
+                        0
+                    } else {
+                        // `start < end`, so this does not actually wrap.
+                        self.end.wrapping_sub(self.start) as usize
+                    }
+                }
+            }
+        )*
+        $(
+            impl CopyRange<$ity> {
+                /// Returns the exact length of the range.
+                ///
+                /// Returns `0` if the range is empty, including if
+                /// `start > end`.
+                pub fn len(&self) -> usize {
+                    self.into_std().len()
+                }
+            }
+        )*
+    };
 }
 
-impl<Idx> IntoIterator for CopyRange<Idx>
-where
-    Range<Idx>: Iterator<Item = Idx>,
-{
-    type Item = Idx;
+impl_len! {
+    unsigned: u8 u16 u32 usize;
+    signed: i8 i16 i32 isize;
+}
 
-    type IntoIter = Range<Idx>;
+/// [`core::ops::RangeFull`] is already `Copy`, so we just reexport it.
+pub type CopyRangeFull = RangeFull;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.start..self.end
+/// The type [`CopyRangeTo`] used to be an alias for, i.e.
+/// [`core::ops::RangeTo`].
+///
+/// This is kept for one release to ease migrating to the struct
+/// `CopyRangeTo`, and will then be removed. Code that needs `RangeTo` itself
+/// should name it directly.
+///
+/// ```rust
+/// # #![allow(deprecated)]
+/// # use copy_range::{CopyRangeTo, CopyRangeToStd};
+/// let old: CopyRangeToStd<u32> = ..3;
+/// let new: CopyRangeTo<u32> = old.into();
+/// assert_eq!(new, CopyRangeTo { end: 3 });
+/// assert_eq!(new.into_std(), old);
+/// ```
+#[deprecated(
+    note = "`CopyRangeTo` is now a struct; use it or `core::ops::RangeTo` \
+            instead"
+)]
+pub type CopyRangeToStd<Idx> = core::ops::RangeTo<Idx>;
+
+impl CopyRange<usize> {
+    /// Clamps the range to be within `0..len`, so that it can be used to index
+    /// a slice of length `len` without panicking.
+    ///
+    /// Both endpoints are clamped to at most `len`, and then `start` is
+    /// clamped to at most `end`, so out-of-bounds or reversed ranges are
+    /// truncated to a (possibly empty) range instead.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let data = [1, 2, 3, 4];
+    /// let range = CopyRange { start: 2, end: 10 };
+    /// assert_eq!(data[range.clamp_to_len(data.len())], [3, 4]);
+    /// let range = CopyRange { start: 3, end: 1 };
+    /// assert_eq!(data[range.clamp_to_len(data.len())], []);
+    /// ```
+    pub fn clamp_to_len(self, len: usize) -> CopyRange<usize> {
+        let end = Ord::min(self.end, len);
+        let start = Ord::min(self.start, end);
+        CopyRange { start, end }
     }
-}
 
-impl<Idx> RangeBounds<Idx> for CopyRangeFrom<Idx> {
-    fn start_bound(&self) -> Bound<&Idx> {
-        Bound::Included(&self.start)
+    /// Returns the subslice of `slice` in this range, or an error describing
+    /// why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, IndexRangeError};
+    /// let data = [1, 2, 3, 4];
+    /// let range = CopyRange { start: 1, end: 3 };
+    /// assert_eq!(range.index_checked(&data[..]), Ok(&[2, 3][..]));
+    /// let range = CopyRange { start: 1, end: 5 };
+    /// assert_eq!(
+    ///     range.index_checked(&data[..]),
+    ///     Err(IndexRangeError::EndOutOfBounds { end: 5, len: 4 }),
+    /// );
+    /// let range = CopyRange { start: 1, end: 2 };
+    /// assert_eq!(
+    ///     range.index_checked("\u{e9}"),
+    ///     Err(IndexRangeError::NotCharBoundary { index: 1 }),
+    /// );
+    /// ```
+    pub fn index_checked<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a S,
+    ) -> Result<&'a S, IndexRangeError> {
+        slice.checked_slice(self.into_std())
     }
 
-    fn end_bound(&self) -> Bound<&Idx> {
-        Bound::Unbounded
+    /// Returns the subslice of `slice` in this range mutably, or an error
+    /// describing why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics.
+    pub fn index_checked_mut<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a mut S,
+    ) -> Result<&'a mut S, IndexRangeError> {
+        slice.checked_slice_mut(self.into_std())
     }
 }
 
-impl<Idx> RangeBounds<Idx> for CopyRangeFrom<&Idx> {
-    fn start_bound(&self) -> Bound<&Idx> {
-        Bound::Included(self.start)
+impl CopyRangeFrom<usize> {
+    /// Clamps the range to be within `0..len`, so that it can be used to index
+    /// a slice of length `len` without panicking.
+    ///
+    /// This returns `start..len`, with `start` clamped to at most `len`.
+    pub fn clamp_to_len(self, len: usize) -> CopyRange<usize> {
+        CopyRange { start: Ord::min(self.start, len), end: len }
     }
 
-    fn end_bound(&self) -> Bound<&Idx> {
-        Bound::Unbounded
+    /// Returns `start..len`, or the empty range `len..len` if `start > len`,
+    /// so that the result can always be used to index a slice of length
+    /// `len`.
+    ///
+    /// This is the same as [`clamp_to_len`][Self::clamp_to_len] and
+    /// [`bounded_by(len)`][Self::bounded_by].
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeFrom};
+    /// let data = [1, 2, 3];
+    /// assert_eq!(data[CopyRangeFrom { start: 1 }.bounded(data.len())], [2, 3]);
+    /// assert_eq!(data[CopyRangeFrom { start: 3 }.bounded(data.len())], []);
+    /// assert_eq!(
+    ///     CopyRangeFrom { start: 5 }.bounded(data.len()),
+    ///     CopyRange { start: 3, end: 3 },
+    /// );
+    /// assert_eq!(
+    ///     CopyRangeFrom { start: 0 }.bounded(0),
+    ///     CopyRange { start: 0, end: 0 }
+    /// );
+    /// ```
+    pub fn bounded(&self, len: usize) -> CopyRange<usize> {
+        self.bounded_by(len)
     }
-}
 
-impl<Idx> IntoIterator for CopyRangeFrom<Idx>
-where
-    RangeFrom<Idx>: Iterator<Item = Idx>,
-{
-    type Item = Idx;
+    /// Returns `start..len`, or an error if `start > len`.
+    ///
+    /// This reports the same error as [`index_checked`][Self::index_checked]
+    /// would for a slice of length `len`.
+    pub fn try_bounded(
+        &self,
+        len: usize,
+    ) -> Result<CopyRange<usize>, IndexRangeError> {
+        self.checked_to_len(len).map(CopyRange::from)
+    }
 
-    type IntoIter = RangeFrom<Idx>;
+    /// Converts this range to the equivalent `CopyRange` for a slice of length
+    /// `len`, or returns an error if `start` is out of bounds.
+    fn checked_to_len(
+        &self,
+        len: usize,
+    ) -> Result<Range<usize>, IndexRangeError> {
+        if self.start > len {
+            Err(IndexRangeError::StartOutOfBounds { start: self.start, len })
+        } else {
+            Ok(self.start..len)
+        }
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.start..
+    /// Returns the subslice of `slice` in this range, or an error describing
+    /// why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics.
+    pub fn index_checked<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a S,
+    ) -> Result<&'a S, IndexRangeError> {
+        slice.checked_slice(self.checked_to_len(slice.slice_len())?)
+    }
+
+    /// Returns the subslice of `slice` in this range mutably, or an error
+    /// describing why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics.
+    pub fn index_checked_mut<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a mut S,
+    ) -> Result<&'a mut S, IndexRangeError> {
+        slice.checked_slice_mut(self.checked_to_len(slice.slice_len())?)
     }
 }
 
-impl<Idx> RangeBounds<Idx> for CopyRangeInclusive<Idx> {
-    fn start_bound(&self) -> Bound<&Idx> {
-        Bound::Included(&self.start)
+impl CopyRangeInclusive<usize> {
+    /// Converts this range to the equivalent exclusive range, or returns an
+    /// error if `end` is `usize::MAX`.
+    fn checked_exclusive(&self) -> Result<Range<usize>, IndexRangeError> {
+        match self.end.checked_add(1) {
+            Some(end) => Ok(self.start..end),
+            None => Err(IndexRangeError::EndOverflow),
+        }
     }
 
-    fn end_bound(&self) -> Bound<&Idx> {
-        Bound::Included(&self.end)
+    /// Returns the subslice of `slice` in this range, or an error describing
+    /// why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics. Errors report the end of the range
+    /// exclusively, i.e. as `end + 1`.
+    pub fn index_checked<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a S,
+    ) -> Result<&'a S, IndexRangeError> {
+        slice.checked_slice(self.checked_exclusive()?)
+    }
+
+    /// Returns the subslice of `slice` in this range mutably, or an error
+    /// describing why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics. Errors report the end of the range
+    /// exclusively, i.e. as `end + 1`.
+    pub fn index_checked_mut<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a mut S,
+    ) -> Result<&'a mut S, IndexRangeError> {
+        slice.checked_slice_mut(self.checked_exclusive()?)
     }
 }
 
-impl<Idx> RangeBounds<Idx> for CopyRangeInclusive<&Idx> {
-    fn start_bound(&self) -> Bound<&Idx> {
-        Bound::Included(self.start)
+impl CopyRangeTo<usize> {
+    /// Returns the subslice of `slice` in this range, or an error describing
+    /// why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics.
+    pub fn index_checked<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a S,
+    ) -> Result<&'a S, IndexRangeError> {
+        CopyRange { start: 0, end: self.end }.index_checked(slice)
     }
 
-    fn end_bound(&self) -> Bound<&Idx> {
-        Bound::Included(self.end)
+    /// Returns the subslice of `slice` in this range mutably, or an error
+    /// describing why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics.
+    pub fn index_checked_mut<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a mut S,
+    ) -> Result<&'a mut S, IndexRangeError> {
+        CopyRange { start: 0, end: self.end }.index_checked_mut(slice)
     }
 }
 
-impl<Idx> IntoIterator for CopyRangeInclusive<Idx>
-where
-    RangeInclusive<Idx>: Iterator<Item = Idx>,
-{
-    type Item = Idx;
-
-    type IntoIter = RangeInclusive<Idx>;
+impl CopyRangeToInclusive<usize> {
+    /// Returns the subslice of `slice` in this range, or an error describing
+    /// why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics. Errors report the end of the range
+    /// exclusively, i.e. as `end + 1`.
+    pub fn index_checked<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a S,
+    ) -> Result<&'a S, IndexRangeError> {
+        CopyRangeInclusive { start: 0, end: self.end }.index_checked(slice)
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.start..=self.end
+    /// Returns the subslice of `slice` in this range mutably, or an error
+    /// describing why the range is not valid for `slice`.
+    ///
+    /// Unlike indexing, this never panics. Errors report the end of the range
+    /// exclusively, i.e. as `end + 1`.
+    pub fn index_checked_mut<'a, S: ?Sized + Sliceable>(
+        &self,
+        slice: &'a mut S,
+    ) -> Result<&'a mut S, IndexRangeError> {
+        CopyRangeInclusive { start: 0, end: self.end }.index_checked_mut(slice)
     }
 }
 
-/// [`core::ops::RangeFull`] is already `Copy`, so we just reexport it.
-pub type CopyRangeFull = RangeFull;
-/// [`core::ops::RangeTo`] is already `Copy` if `Idx` is `Copy`, so we just
-/// reexport it.
-pub type CopyRangeTo<Idx> = RangeTo<Idx>;
-/// [`core::ops::RangeToInclusive`] is already `Copy` if `Idx` is `Copy`, so we
-/// just reexport it.
-pub type CopyRangeToInclusive<Idx> = RangeToInclusive<Idx>;
-
 macro_rules! impl_index {
     ([$($generics:tt)*], $ty:ty) => {
         impl<$($generics)*> Index<CopyRange<usize>> for $ty
@@ -405,6 +4805,68 @@ macro_rules! impl_index {
                 self.index_mut(index.into_std())
             }
         }
+        impl<$($generics)*> Index<CopyRangeTo<usize>> for $ty
+        where
+            $ty: Index<RangeTo<usize>>,
+        {
+            type Output = <$ty as Index<RangeTo<usize>>>::Output;
+
+            fn index(&self, index: CopyRangeTo<usize>) -> &Self::Output {
+                self.index(index.into_std())
+            }
+        }
+        impl<$($generics)*> IndexMut<CopyRangeTo<usize>> for $ty
+        where
+            $ty: IndexMut<RangeTo<usize>>,
+        {
+            fn index_mut(&mut self, index: CopyRangeTo<usize>) -> &mut Self::Output {
+                self.index_mut(index.into_std())
+            }
+        }
+        impl<$($generics)*> Index<CopyRangeToInclusive<usize>> for $ty
+        where
+            $ty: Index<RangeToInclusive<usize>>,
+        {
+            type Output = <$ty as Index<RangeToInclusive<usize>>>::Output;
+
+            fn index(&self, index: CopyRangeToInclusive<usize>) -> &Self::Output {
+                self.index(index.into_std())
+            }
+        }
+        impl<$($generics)*> IndexMut<CopyRangeToInclusive<usize>> for $ty
+        where
+            $ty: IndexMut<RangeToInclusive<usize>>,
+        {
+            fn index_mut(&mut self, index: CopyRangeToInclusive<usize>) -> &mut Self::Output {
+                self.index_mut(index.into_std())
+            }
+        }
+        impl<$($generics)*> Index<AnyCopyRange<usize>> for $ty {
+            type Output = <$ty as Index<Range<usize>>>::Output;
+
+            fn index(&self, index: AnyCopyRange<usize>) -> &Self::Output {
+                match index {
+                    AnyCopyRange::Full => self.index(..),
+                    AnyCopyRange::From(range) => self.index(range),
+                    AnyCopyRange::To(range) => self.index(range),
+                    AnyCopyRange::ToInclusive(range) => self.index(range),
+                    AnyCopyRange::Range(range) => self.index(range),
+                    AnyCopyRange::Inclusive(range) => self.index(range),
+                }
+            }
+        }
+        impl<$($generics)*> IndexMut<AnyCopyRange<usize>> for $ty {
+            fn index_mut(&mut self, index: AnyCopyRange<usize>) -> &mut Self::Output {
+                match index {
+                    AnyCopyRange::Full => self.index_mut(..),
+                    AnyCopyRange::From(range) => self.index_mut(range),
+                    AnyCopyRange::To(range) => self.index_mut(range),
+                    AnyCopyRange::ToInclusive(range) => self.index_mut(range),
+                    AnyCopyRange::Range(range) => self.index_mut(range),
+                    AnyCopyRange::Inclusive(range) => self.index_mut(range),
+                }
+            }
+        }
     };
 }
 
@@ -414,3 +4876,43 @@ impl_index!([], str);
 impl_index!([T], ::alloc::vec::Vec<T>);
 #[cfg(feature = "alloc")]
 impl_index!([], ::alloc::string::String);
+#[cfg(feature = "smallvec")]
+impl_index!([A: ::smallvec::Array], ::smallvec::SmallVec<A>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{CopyRange, CopyRangeInclusive};
+
+    /// Checks `contains_branchless` against `contains` for every range and
+    /// item of the 8-bit integer types.
+    macro_rules! check_contains_branchless_exhaustive {
+        ($($name:ident: $ty:ident;)*) => {$(
+            #[test]
+            fn $name() {
+                for start in $ty::MIN..=$ty::MAX {
+                    for end in $ty::MIN..=$ty::MAX {
+                        let range = CopyRange { start, end };
+                        let inclusive = CopyRangeInclusive { start, end };
+                        for item in $ty::MIN..=$ty::MAX {
+                            assert_eq!(
+                                range.contains_branchless(item),
+                                range.contains(&item),
+                                "{range:?} contains {item}",
+                            );
+                            assert_eq!(
+                                inclusive.contains_branchless(item),
+                                inclusive.contains(&item),
+                                "{inclusive:?} contains {item}",
+                            );
+                        }
+                    }
+                }
+            }
+        )*};
+    }
+
+    check_contains_branchless_exhaustive! {
+        contains_branchless_u8: u8;
+        contains_branchless_i8: i8;
+    }
+}
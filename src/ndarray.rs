@@ -0,0 +1,60 @@
+use ndarray::{Slice, SliceInfoElem};
+
+use crate::{
+    AnyCopyRange, CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo,
+    CopyRangeToInclusive,
+};
+
+/// Implements `From` for `ndarray`'s slice types for ranges of the index types
+/// `ndarray` supports, by converting them to the `core::ops` range types, so
+/// that negative endpoints etc. are handled exactly like `ndarray` handles
+/// them for those.
+macro_rules! impl_from_range {
+    ($target:ident; $($idx:ident)*) => {$(
+        impl From<CopyRange<$idx>> for $target {
+            fn from(range: CopyRange<$idx>) -> Self {
+                range.into_std().into()
+            }
+        }
+
+        impl From<CopyRangeFrom<$idx>> for $target {
+            fn from(range: CopyRangeFrom<$idx>) -> Self {
+                range.into_std().into()
+            }
+        }
+
+        impl From<CopyRangeInclusive<$idx>> for $target {
+            fn from(range: CopyRangeInclusive<$idx>) -> Self {
+                range.into_std().into()
+            }
+        }
+
+        impl From<CopyRangeTo<$idx>> for $target {
+            fn from(range: CopyRangeTo<$idx>) -> Self {
+                range.into_std().into()
+            }
+        }
+
+        impl From<CopyRangeToInclusive<$idx>> for $target {
+            fn from(range: CopyRangeToInclusive<$idx>) -> Self {
+                range.into_std().into()
+            }
+        }
+
+        impl From<AnyCopyRange<$idx>> for $target {
+            fn from(range: AnyCopyRange<$idx>) -> Self {
+                match range {
+                    AnyCopyRange::Full => (..).into(),
+                    AnyCopyRange::From(range) => range.into(),
+                    AnyCopyRange::To(range) => range.into(),
+                    AnyCopyRange::ToInclusive(range) => range.into(),
+                    AnyCopyRange::Range(range) => range.into(),
+                    AnyCopyRange::Inclusive(range) => range.into(),
+                }
+            }
+        }
+    )*};
+}
+
+impl_from_range!(Slice; isize usize i32);
+impl_from_range!(SliceInfoElem; isize usize i32);
@@ -0,0 +1,113 @@
+use core::range::{Range, RangeFrom, RangeInclusive};
+
+use crate::{CopyRange, CopyRangeFrom, CopyRangeInclusive};
+
+impl<Idx> CopyRange<Idx> {
+    /// Convert a [`core::range::Range`] into a `CopyRange`.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRange;
+    /// let range =
+    ///     CopyRange::from_new_std(core::range::Range { start: 1, end: 4 });
+    /// assert_eq!(range, CopyRange { start: 1, end: 4 });
+    /// assert_eq!(range.into_new_std(), core::range::Range::from(1..4));
+    /// ```
+    pub fn from_new_std(range: Range<Idx>) -> Self {
+        range.into()
+    }
+
+    /// Convert a `CopyRange` into a [`core::range::Range`].
+    pub fn into_new_std(self) -> Range<Idx> {
+        self.into()
+    }
+}
+
+impl<Idx> CopyRangeFrom<Idx> {
+    /// Convert a [`core::range::RangeFrom`] into a `CopyRangeFrom`.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeFrom;
+    /// let range =
+    ///     CopyRangeFrom::from_new_std(core::range::RangeFrom { start: 1 });
+    /// assert_eq!(range, CopyRangeFrom { start: 1 });
+    /// assert_eq!(range.into_new_std(), core::range::RangeFrom::from(1..));
+    /// ```
+    pub fn from_new_std(range: RangeFrom<Idx>) -> Self {
+        range.into()
+    }
+
+    /// Convert a `CopyRangeFrom` into a [`core::range::RangeFrom`].
+    pub fn into_new_std(self) -> RangeFrom<Idx> {
+        self.into()
+    }
+}
+
+impl<Idx> CopyRangeInclusive<Idx> {
+    /// Convert a [`core::range::RangeInclusive`] into a `CopyRangeInclusive`.
+    ///
+    /// Unlike [`from_std`](Self::from_std), this is always lossless, since
+    /// `core::range::RangeInclusive` is not an iterator and so is never
+    /// exhausted.
+    ///
+    /// ```rust
+    /// # use copy_range::CopyRangeInclusive;
+    /// let range = CopyRangeInclusive::from_new_std(core::range::RangeInclusive {
+    ///     start: 1,
+    ///     last: 4,
+    /// });
+    /// assert_eq!(range, CopyRangeInclusive { start: 1, end: 4 });
+    /// assert_eq!(range.into_new_std(), core::range::RangeInclusive::from(1..=4));
+    /// ```
+    pub fn from_new_std(range: RangeInclusive<Idx>) -> Self {
+        range.into()
+    }
+
+    /// Convert a `CopyRangeInclusive` into a [`core::range::RangeInclusive`].
+    pub fn into_new_std(self) -> RangeInclusive<Idx> {
+        self.into()
+    }
+}
+
+/// Convert a [`core::range::Range`] into a `CopyRange`.
+impl<Idx> From<Range<Idx>> for CopyRange<Idx> {
+    fn from(Range { start, end }: Range<Idx>) -> Self {
+        Self { start, end }
+    }
+}
+
+/// Convert a `CopyRange` into a [`core::range::Range`].
+impl<Idx> From<CopyRange<Idx>> for Range<Idx> {
+    fn from(CopyRange { start, end }: CopyRange<Idx>) -> Self {
+        Range { start, end }
+    }
+}
+
+/// Convert a [`core::range::RangeFrom`] into a `CopyRangeFrom`.
+impl<Idx> From<RangeFrom<Idx>> for CopyRangeFrom<Idx> {
+    fn from(RangeFrom { start }: RangeFrom<Idx>) -> Self {
+        Self { start }
+    }
+}
+
+/// Convert a `CopyRangeFrom` into a [`core::range::RangeFrom`].
+impl<Idx> From<CopyRangeFrom<Idx>> for RangeFrom<Idx> {
+    fn from(CopyRangeFrom { start }: CopyRangeFrom<Idx>) -> Self {
+        RangeFrom { start }
+    }
+}
+
+/// Convert a [`core::range::RangeInclusive`] into a `CopyRangeInclusive`.
+impl<Idx> From<RangeInclusive<Idx>> for CopyRangeInclusive<Idx> {
+    fn from(RangeInclusive { start, last }: RangeInclusive<Idx>) -> Self {
+        Self { start, end: last }
+    }
+}
+
+/// Convert a `CopyRangeInclusive` into a [`core::range::RangeInclusive`].
+impl<Idx> From<CopyRangeInclusive<Idx>> for RangeInclusive<Idx> {
+    fn from(
+        CopyRangeInclusive { start, end }: CopyRangeInclusive<Idx>,
+    ) -> Self {
+        RangeInclusive { start, last: end }
+    }
+}
@@ -0,0 +1,150 @@
+use num_traits::PrimInt;
+
+use crate::{CopyRange, CopyRangeInclusive};
+
+/// Integer range operations which are generic over [`PrimInt`].
+///
+/// Integer ranges also have inherent methods for these operations, which do
+/// not require the `"num-traits"` feature, but they are implemented separately
+/// for each primitive integer type and so cannot be used in generic code.
+/// [`is_empty`][CopyRange::is_empty] does not need a separate method here,
+/// since it only requires `PartialOrd`.
+pub trait PrimIntRange<T: PrimInt>: Sized {
+    /// Returns the number of elements in the range, or `None` if it does not
+    /// fit in a `usize`.
+    ///
+    /// Returns `Some(0)` if the range is empty, including if it is reversed.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeInclusive, PrimIntRange};
+    /// let range = CopyRange { start: 0u8, end: u8::MAX };
+    /// assert_eq!(range.checked_len(), Some(255));
+    /// let range = CopyRangeInclusive { start: 0u8, end: u8::MAX };
+    /// assert_eq!(range.checked_len(), Some(256));
+    /// let range = CopyRange { start: 5i8, end: -5 };
+    /// assert_eq!(range.checked_len(), Some(0));
+    /// let range = CopyRangeInclusive { start: 5i8, end: -5 };
+    /// assert_eq!(range.checked_len(), Some(0));
+    ///
+    /// // The number of elements does not fit in a `usize`.
+    /// let range = CopyRangeInclusive { start: i64::MIN, end: i64::MAX };
+    /// assert_eq!(range.checked_len(), None);
+    /// let range = CopyRange { start: u128::MIN, end: u128::MAX };
+    /// assert_eq!(range.checked_len(), None);
+    /// ```
+    fn checked_len(&self) -> Option<usize>;
+
+    /// Adds `offset` to both endpoints of the range, or returns `None` if
+    /// either overflows.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeInclusive, PrimIntRange};
+    /// // The inherent methods of the same name take precedence for concrete
+    /// // types, so the trait is named explicitly here.
+    /// let range = CopyRange { start: 0u8, end: 10 };
+    /// assert_eq!(
+    ///     PrimIntRange::checked_add_offset(range, 245),
+    ///     Some(CopyRange { start: 245, end: u8::MAX }),
+    /// );
+    /// assert_eq!(PrimIntRange::checked_add_offset(range, 246), None);
+    ///
+    /// let range = CopyRangeInclusive { start: i8::MIN, end: 0 };
+    /// assert_eq!(
+    ///     PrimIntRange::checked_add_offset(range, i8::MAX),
+    ///     Some(CopyRangeInclusive { start: -1, end: i8::MAX }),
+    /// );
+    /// // An inclusive range ending at `MAX` cannot be offset further.
+    /// let range = CopyRangeInclusive { start: 0i8, end: i8::MAX };
+    /// assert_eq!(PrimIntRange::checked_add_offset(range, 1), None);
+    /// // A negative offset can overflow the start instead.
+    /// let range = CopyRangeInclusive { start: i8::MIN, end: 0 };
+    /// assert_eq!(PrimIntRange::checked_add_offset(range, -1), None);
+    /// ```
+    fn checked_add_offset(self, offset: T) -> Option<Self>;
+
+    /// Subtracts `offset` from both endpoints of the range, or returns `None`
+    /// if either overflows.
+    ///
+    /// ```rust
+    /// # use copy_range::{CopyRange, CopyRangeInclusive, PrimIntRange};
+    /// let range = CopyRange { start: 10u8, end: 20 };
+    /// assert_eq!(
+    ///     PrimIntRange::checked_sub_offset(range, 10),
+    ///     Some(CopyRange { start: 0, end: 10 }),
+    /// );
+    /// assert_eq!(PrimIntRange::checked_sub_offset(range, 11), None);
+    ///
+    /// let range = CopyRangeInclusive { start: 0i8, end: i8::MAX };
+    /// assert_eq!(
+    ///     PrimIntRange::checked_sub_offset(range, 1),
+    ///     Some(CopyRangeInclusive { start: -1, end: i8::MAX - 1 }),
+    /// );
+    /// // Subtracting `MIN` or a negative offset moves the range up, past `MAX`.
+    /// assert_eq!(PrimIntRange::checked_sub_offset(range, i8::MIN), None);
+    /// assert_eq!(PrimIntRange::checked_sub_offset(range, -1), None);
+    /// let range = CopyRangeInclusive { start: i8::MIN, end: i8::MIN };
+    /// assert_eq!(PrimIntRange::checked_sub_offset(range, 1), None);
+    /// ```
+    fn checked_sub_offset(self, offset: T) -> Option<Self>;
+}
+
+/// Returns `end - start` as a `u128`, which cannot overflow for primitive
+/// integers if `start <= end`.
+fn distance<T: PrimInt>(start: T, end: T) -> Option<u128> {
+    if T::min_value() < T::zero() {
+        let start = start.to_i128()?;
+        let end = end.to_i128()?;
+        Some(end.abs_diff(start))
+    } else {
+        Some(end.to_u128()? - start.to_u128()?)
+    }
+}
+
+impl<T: PrimInt> PrimIntRange<T> for CopyRange<T> {
+    fn checked_len(&self) -> Option<usize> {
+        if self.start < self.end {
+            usize::try_from(distance(self.start, self.end)?).ok()
+        } else {
+            Some(0)
+        }
+    }
+
+    fn checked_add_offset(self, offset: T) -> Option<Self> {
+        Some(CopyRange {
+            start: self.start.checked_add(&offset)?,
+            end: self.end.checked_add(&offset)?,
+        })
+    }
+
+    fn checked_sub_offset(self, offset: T) -> Option<Self> {
+        Some(CopyRange {
+            start: self.start.checked_sub(&offset)?,
+            end: self.end.checked_sub(&offset)?,
+        })
+    }
+}
+
+impl<T: PrimInt> PrimIntRange<T> for CopyRangeInclusive<T> {
+    fn checked_len(&self) -> Option<usize> {
+        if self.start <= self.end {
+            usize::try_from(distance(self.start, self.end)?.checked_add(1)?)
+                .ok()
+        } else {
+            Some(0)
+        }
+    }
+
+    fn checked_add_offset(self, offset: T) -> Option<Self> {
+        Some(CopyRangeInclusive {
+            start: self.start.checked_add(&offset)?,
+            end: self.end.checked_add(&offset)?,
+        })
+    }
+
+    fn checked_sub_offset(self, offset: T) -> Option<Self> {
+        Some(CopyRangeInclusive {
+            start: self.start.checked_sub(&offset)?,
+            end: self.end.checked_sub(&offset)?,
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use core::ops::{RangeBounds, RangeFrom, RangeTo, RangeToInclusive};
+
+use crate::{CopyRangeFrom, CopyRangeTo, CopyRangeToInclusive};
+
+mod sealed {
+    use core::ops::{RangeFrom, RangeTo, RangeToInclusive};
+
+    use crate::{CopyRangeFrom, CopyRangeTo, CopyRangeToInclusive};
+
+    pub trait Sealed {}
+
+    impl<Idx> Sealed for RangeFrom<Idx> {}
+    impl<Idx> Sealed for RangeTo<Idx> {}
+    impl<Idx> Sealed for RangeToInclusive<Idx> {}
+    impl<Idx> Sealed for CopyRangeFrom<Idx> {}
+    impl<Idx> Sealed for CopyRangeTo<Idx> {}
+    impl<Idx> Sealed for CopyRangeToInclusive<Idx> {}
+}
+
+/// Which bound of a [`OneSidedCopyRange`] is present.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SplitDirection {
+    /// The range is bounded inclusively below and unbounded above
+    /// (`start..`).
+    StartInclusive,
+    /// The range is unbounded below and bounded exclusively above (`..end`).
+    End,
+    /// The range is unbounded below and bounded inclusively above
+    /// (`..=end`).
+    EndInclusive,
+}
+
+/// Ranges which are bounded on exactly one side. See
+/// [`core::ops::OneSidedRange`].
+///
+/// This is implemented for [`CopyRangeFrom`], [`CopyRangeTo`] and
+/// [`CopyRangeToInclusive`], and their `core::ops` counterparts, and cannot be
+/// implemented outside of this crate.
+pub trait OneSidedCopyRange<Idx>: RangeBounds<Idx> + sealed::Sealed {
+    /// Returns the bound of the range, and which kind of bound it is.
+    fn split_point(&self) -> (SplitDirection, Idx)
+    where
+        Idx: Copy;
+}
+
+impl<Idx> OneSidedCopyRange<Idx> for RangeFrom<Idx> {
+    fn split_point(&self) -> (SplitDirection, Idx)
+    where
+        Idx: Copy,
+    {
+        (SplitDirection::StartInclusive, self.start)
+    }
+}
+
+impl<Idx> OneSidedCopyRange<Idx> for RangeTo<Idx> {
+    fn split_point(&self) -> (SplitDirection, Idx)
+    where
+        Idx: Copy,
+    {
+        (SplitDirection::End, self.end)
+    }
+}
+
+impl<Idx> OneSidedCopyRange<Idx> for RangeToInclusive<Idx> {
+    fn split_point(&self) -> (SplitDirection, Idx)
+    where
+        Idx: Copy,
+    {
+        (SplitDirection::EndInclusive, self.end)
+    }
+}
+
+impl<Idx> OneSidedCopyRange<Idx> for CopyRangeFrom<Idx> {
+    fn split_point(&self) -> (SplitDirection, Idx)
+    where
+        Idx: Copy,
+    {
+        (SplitDirection::StartInclusive, self.start)
+    }
+}
+
+impl<Idx> OneSidedCopyRange<Idx> for CopyRangeTo<Idx> {
+    fn split_point(&self) -> (SplitDirection, Idx)
+    where
+        Idx: Copy,
+    {
+        (SplitDirection::End, self.end)
+    }
+}
+
+impl<Idx> OneSidedCopyRange<Idx> for CopyRangeToInclusive<Idx> {
+    fn split_point(&self) -> (SplitDirection, Idx)
+    where
+        Idx: Copy,
+    {
+        (SplitDirection::EndInclusive, self.end)
+    }
+}
+
+/// Splits `slice` into the elements before and after the bound of `range`.
+///
+/// The split is at `start` for `start..` ranges, at `end` for `..end` ranges,
+/// and at `end + 1` for `..=end` ranges, so `range` indexes exactly one of the
+/// two halves.
+///
+/// Panics if the split point is greater than `slice.len()`, like
+/// [`<[T]>::split_at`](slice::split_at).
+///
+/// ```rust
+/// # use copy_range::{split_slice_at, CopyRangeFrom, CopyRangeTo, CopyRangeToInclusive};
+/// let data = [1, 2, 3, 4, 5];
+/// assert_eq!(split_slice_at(&data, 2..), (&data[..2], &data[2..]));
+/// assert_eq!(split_slice_at(&data, ..2), (&data[..2], &data[2..]));
+/// assert_eq!(split_slice_at(&data, ..=2), (&data[..3], &data[3..]));
+/// assert_eq!(split_slice_at(&data, CopyRangeFrom { start: 5 }), (&data[..], &[][..]));
+/// assert_eq!(split_slice_at(&data, CopyRangeTo { end: 0 }), (&[][..], &data[..]));
+/// assert_eq!(split_slice_at(&data, CopyRangeToInclusive { end: 4 }), (&data[..], &[][..]));
+/// ```
+pub fn split_slice_at<T, R>(slice: &[T], range: R) -> (&[T], &[T])
+where
+    R: OneSidedCopyRange<usize>,
+{
+    let mid = match range.split_point() {
+        (SplitDirection::StartInclusive | SplitDirection::End, mid) => mid,
+        (SplitDirection::EndInclusive, end) => match end.checked_add(1) {
+            Some(mid) => mid,
+            None => panic!("attempted to index slice up to maximum usize"),
+        },
+    };
+    slice.split_at(mid)
+}
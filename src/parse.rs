@@ -0,0 +1,164 @@
+use core::str::FromStr;
+
+use crate::{
+    AnyCopyRange, CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo,
+    CopyRangeToInclusive, ParseCopyRangeError,
+};
+
+/// The parts of a range string, as split by [`split_range`].
+struct RangeParts<'a> {
+    start: &'a str,
+    inclusive: bool,
+    end: &'a str,
+}
+
+/// Splits `s` at the first `..` (or `..=`), returning the (trimmed) text
+/// before and after it.
+///
+/// Endpoints can't contain `..` themselves, so splitting at the first one
+/// keeps negative and fractional endpoints like `-1.5` intact.
+fn split_range<E>(s: &str) -> Result<RangeParts<'_>, ParseCopyRangeError<E>> {
+    let (start, end) =
+        s.split_once("..").ok_or(ParseCopyRangeError::MissingSeparator)?;
+    let (inclusive, end) = match end.strip_prefix('=') {
+        Some(end) => (true, end),
+        None => (false, end),
+    };
+    Ok(RangeParts { start: start.trim(), inclusive, end: end.trim() })
+}
+
+fn parse_start<Idx: FromStr>(
+    start: &str,
+) -> Result<Idx, ParseCopyRangeError<Idx::Err>> {
+    start.parse().map_err(ParseCopyRangeError::InvalidStart)
+}
+
+fn parse_end<Idx: FromStr>(
+    end: &str,
+) -> Result<Idx, ParseCopyRangeError<Idx::Err>> {
+    end.parse().map_err(ParseCopyRangeError::InvalidEnd)
+}
+
+/// Parses a range of the form `start..end`, with optional whitespace around
+/// each endpoint. See [`ParseCopyRangeError`] for examples.
+///
+/// Both endpoints are required: `"..5"` and `"2.."` are errors rather than
+/// having a default endpoint, even if `Idx` can be parsed from an empty
+/// string.
+impl<Idx: FromStr> FromStr for CopyRange<Idx> {
+    type Err = ParseCopyRangeError<Idx::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let RangeParts { start, inclusive, end } = split_range(s)?;
+        if inclusive {
+            return Err(ParseCopyRangeError::InclusiveSeparator);
+        }
+        if start.is_empty() {
+            return Err(ParseCopyRangeError::EmptyStart);
+        }
+        if end.is_empty() {
+            return Err(ParseCopyRangeError::EmptyEnd);
+        }
+        Ok(CopyRange { start: parse_start(start)?, end: parse_end(end)? })
+    }
+}
+
+/// Parses a range in any of the six range syntaxes: `start..end`,
+/// `start..=end`, `start..`, `..end`, `..=end` and `..`, with optional
+/// whitespace around each endpoint.
+///
+/// The string is split at the first `..`, and if that is followed by `=`,
+/// the range is inclusive. An empty (or whitespace-only) endpoint means that
+/// side is unbounded, so `".."` is [`Full`](AnyCopyRange::Full). Inclusive
+/// ranges must have an end, so `"..="` and `"5..="` are errors. Any other
+/// text on either side is parsed as `Idx`, even if it contains `..` or
+/// starts with `=`.
+///
+/// ```rust
+/// # use copy_range::{AnyCopyRange, CopyRange, CopyRangeFrom, CopyRangeInclusive, CopyRangeTo, CopyRangeToInclusive, ParseCopyRangeError};
+/// use AnyCopyRange::*;
+/// use ParseCopyRangeError::*;
+/// let cases: &[(&str, Result<AnyCopyRange<i32>, ParseCopyRangeError<()>>)] = &[
+///     ("2..5", Ok(Range(CopyRange { start: 2, end: 5 }))),
+///     ("2..=5", Ok(Inclusive(CopyRangeInclusive { start: 2, end: 5 }))),
+///     ("2..", Ok(From(CopyRangeFrom { start: 2 }))),
+///     ("..5", Ok(To(CopyRangeTo { end: 5 }))),
+///     ("..=5", Ok(ToInclusive(CopyRangeToInclusive { end: 5 }))),
+///     ("..", Ok(Full)),
+///     ("-5..", Ok(From(CopyRangeFrom { start: -5 }))),
+///     ("-5..-2", Ok(Range(CopyRange { start: -5, end: -2 }))),
+///     ("-5..=-2", Ok(Inclusive(CopyRangeInclusive { start: -5, end: -2 }))),
+///     ("..-2", Ok(To(CopyRangeTo { end: -2 }))),
+///     ("..=-2", Ok(ToInclusive(CopyRangeToInclusive { end: -2 }))),
+///     (" 2 .. 5 ", Ok(Range(CopyRange { start: 2, end: 5 }))),
+///     (" 2 ..= 5 ", Ok(Inclusive(CopyRangeInclusive { start: 2, end: 5 }))),
+///     (" .. ", Ok(Full)),
+///     ("+2..+5", Ok(Range(CopyRange { start: 2, end: 5 }))),
+///     ("5..2", Ok(Range(CopyRange { start: 5, end: 2 }))),
+///     ("", Err(MissingSeparator)),
+///     ("5", Err(MissingSeparator)),
+///     ("2-5", Err(MissingSeparator)),
+///     ("2.5", Err(MissingSeparator)),
+///     ("..=", Err(EmptyEnd)),
+///     ("2..=", Err(EmptyEnd)),
+///     (" ..= ", Err(EmptyEnd)),
+///     ("a..5", Err(InvalidStart(()))),
+///     ("a..b", Err(InvalidStart(()))),
+///     ("a..b..c", Err(InvalidStart(()))),
+///     ("1..2..3", Err(InvalidEnd(()))),
+///     ("2...5", Err(InvalidEnd(()))),
+///     ("2..==5", Err(InvalidEnd(()))),
+///     ("2..5=", Err(InvalidEnd(()))),
+///     ("..b", Err(InvalidEnd(()))),
+///     ("..=b", Err(InvalidEnd(()))),
+///     ("2 3..5", Err(InvalidStart(()))),
+///     ("3000000000..", Err(InvalidStart(()))),
+///     ("..3000000000", Err(InvalidEnd(()))),
+/// ];
+/// for (input, expected) in cases {
+///     let result = input
+///         .parse::<AnyCopyRange<i32>>()
+///         .map_err(|err| match err {
+///             InvalidStart(_) => InvalidStart(()),
+///             InvalidEnd(_) => InvalidEnd(()),
+///             MissingSeparator => MissingSeparator,
+///             EmptyEnd => EmptyEnd,
+///             err => panic!("unexpected error {err:?}"),
+///         });
+///     assert_eq!(&result, expected, "{input:?}");
+/// }
+///
+/// // The error says which endpoint failed to parse.
+/// let err = "1..x".parse::<AnyCopyRange<u8>>().unwrap_err();
+/// assert_eq!(err.to_string(), "invalid range end: invalid digit found in string");
+///
+/// // Only one `=` is part of the separator; the rest is parsed as the end.
+/// assert_eq!("a..=b".parse(), Ok(Inclusive(CopyRangeInclusive { start: 'a', end: 'b' })));
+/// assert_eq!("..==".parse(), Ok(ToInclusive(CopyRangeToInclusive { end: '=' })));
+/// ```
+impl<Idx: FromStr> FromStr for AnyCopyRange<Idx> {
+    type Err = ParseCopyRangeError<Idx::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let RangeParts { start, inclusive, end } = split_range(s)?;
+        let start = match start {
+            "" => None,
+            start => Some(parse_start(start)?),
+        };
+        Ok(match (start, inclusive, end) {
+            (_, true, "") => return Err(ParseCopyRangeError::EmptyEnd),
+            (None, false, "") => AnyCopyRange::Full,
+            (Some(start), false, "") => CopyRangeFrom { start }.into(),
+            (None, false, end) => CopyRangeTo { end: parse_end(end)? }.into(),
+            (None, true, end) => {
+                CopyRangeToInclusive { end: parse_end(end)? }.into()
+            }
+            (Some(start), false, end) => {
+                CopyRange { start, end: parse_end(end)? }.into()
+            }
+            (Some(start), true, end) => {
+                CopyRangeInclusive { start, end: parse_end(end)? }.into()
+            }
+        })
+    }
+}
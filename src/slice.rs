@@ -0,0 +1,179 @@
+use core::{mem, ops::Range};
+
+use crate::{CopyRange, IndexRangeError};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T> Sealed for [T] {}
+    impl Sealed for str {}
+}
+
+/// Types which ranges of `usize` can be used to index without panicking, via
+/// e.g. [`CopyRange::index_checked`][crate::CopyRange::index_checked].
+///
+/// This is implemented for [slices](prim@slice) and
+/// [string slices](prim@str), and cannot be implemented outside of this crate.
+pub trait Sliceable: sealed::Sealed {
+    #[doc(hidden)]
+    fn slice_len(&self) -> usize;
+
+    #[doc(hidden)]
+    fn checked_slice(
+        &self,
+        range: Range<usize>,
+    ) -> Result<&Self, IndexRangeError>;
+
+    #[doc(hidden)]
+    fn checked_slice_mut(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<&mut Self, IndexRangeError>;
+}
+
+impl<T> Sliceable for [T] {
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+
+    fn checked_slice(
+        &self,
+        range: Range<usize>,
+    ) -> Result<&Self, IndexRangeError> {
+        let range = check_range(range, self.len())?;
+        Ok(&self[range])
+    }
+
+    fn checked_slice_mut(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<&mut Self, IndexRangeError> {
+        let range = check_range(range, self.len())?;
+        Ok(&mut self[range])
+    }
+}
+
+impl Sliceable for str {
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+
+    fn checked_slice(
+        &self,
+        range: Range<usize>,
+    ) -> Result<&Self, IndexRangeError> {
+        let range = check_str_range(self, range)?;
+        Ok(&self[range])
+    }
+
+    fn checked_slice_mut(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<&mut Self, IndexRangeError> {
+        let range = check_str_range(self, range)?;
+        Ok(&mut self[range])
+    }
+}
+
+/// Checks that `range` can be used to index a slice of length `len` without
+/// panicking, in the same order as the `Index` impls do.
+pub(crate) fn check_range(
+    range: Range<usize>,
+    len: usize,
+) -> Result<Range<usize>, IndexRangeError> {
+    if range.start > range.end {
+        Err(IndexRangeError::StartGreaterThanEnd {
+            start: range.start,
+            end: range.end,
+        })
+    } else if range.end > len {
+        Err(IndexRangeError::EndOutOfBounds { end: range.end, len })
+    } else {
+        Ok(range)
+    }
+}
+
+fn check_str_range(
+    s: &str,
+    range: Range<usize>,
+) -> Result<Range<usize>, IndexRangeError> {
+    let range = check_range(range, s.len())?;
+    for index in [range.start, range.end] {
+        if !s.is_char_boundary(index) {
+            return Err(IndexRangeError::NotCharBoundary { index });
+        }
+    }
+    Ok(range)
+}
+
+/// Returns mutable references to the subslices of `slice` in each of
+/// `ranges` at once.
+///
+/// Returns `None` if any range is out of bounds or reversed, or if any two
+/// ranges [overlap][CopyRange::overlaps]. Empty ranges never overlap anything,
+/// so they may be anywhere within the slice.
+///
+/// This is similar to [`<[T]>::get_disjoint_mut`][slice::get_disjoint_mut],
+/// but for `CopyRange`s, and does not use `unsafe`.
+///
+/// ```rust
+/// # use copy_range::{copy_get_disjoint_mut, CopyRange};
+/// let mut data = [0, 1, 2, 3, 4, 5];
+/// let [a, b] = copy_get_disjoint_mut(
+///     &mut data,
+///     [CopyRange { start: 3, end: 5 }, CopyRange { start: 0, end: 2 }],
+/// )
+/// .unwrap();
+/// a.swap_with_slice(b);
+/// assert_eq!(data, [3, 4, 2, 0, 1, 5]);
+///
+/// // Overlapping or out-of-bounds ranges are rejected.
+/// let ranges = [
+///     CopyRange { start: 0, end: 2 },
+///     CopyRange { start: 4, end: 6 },
+///     CopyRange { start: 1, end: 3 },
+/// ];
+/// assert!(copy_get_disjoint_mut(&mut data, ranges).is_none());
+/// let ranges =
+///     [CopyRange { start: 4, end: 7 }, CopyRange { start: 0, end: 2 }];
+/// assert!(copy_get_disjoint_mut(&mut data, ranges).is_none());
+///
+/// // No ranges at all is trivially fine.
+/// assert_eq!(copy_get_disjoint_mut(&mut data, []), Some([]));
+/// ```
+pub fn copy_get_disjoint_mut<T, const N: usize>(
+    slice: &mut [T],
+    ranges: [CopyRange<usize>; N],
+) -> Option<[&mut [T]; N]> {
+    let len = slice.len();
+    for (i, range) in ranges.iter().enumerate() {
+        if range.start > range.end || range.end > len {
+            return None;
+        }
+        if ranges[..i].iter().any(|other| range.overlaps(other)) {
+            return None;
+        }
+    }
+
+    // Split off the non-empty ranges in increasing order, so that each one
+    // starts at or after the end of the previous one.
+    let mut order: [usize; N] = core::array::from_fn(|i| i);
+    order.sort_unstable_by_key(|&i| ranges[i].start);
+    let mut subslices: [Option<&mut [T]>; N] = core::array::from_fn(|_| None);
+    let mut rest = slice;
+    let mut consumed = 0;
+    for i in order {
+        let range = ranges[i];
+        if range.is_empty() {
+            subslices[i] = Some(&mut []);
+            continue;
+        }
+        let (_, tail) =
+            mem::take(&mut rest).split_at_mut(range.start - consumed);
+        let (subslice, tail) = tail.split_at_mut(range.end - range.start);
+        subslices[i] = Some(subslice);
+        rest = tail;
+        consumed = range.end;
+    }
+    Some(subslices.map(|subslice| subslice.unwrap()))
+}
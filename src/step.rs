@@ -0,0 +1,49 @@
+/// Types with a notion of the next and previous value, used to convert
+/// between excluded and included bounds.
+///
+/// This is implemented for the primitive integer types and `char` (for which
+/// the surrogate code points are skipped, like `Range<char>` does).
+///
+/// Types for which excluded and included bounds cannot be converted can
+/// implement this by returning `None` from both methods, so that only
+/// conversions between bounds of the same kind succeed.
+pub trait CopyStep: Sized {
+    /// Returns the smallest value greater than `self`, or `None` if there is
+    /// none.
+    fn checked_successor(&self) -> Option<Self>;
+
+    /// Returns the largest value less than `self`, or `None` if there is none.
+    fn checked_predecessor(&self) -> Option<Self>;
+}
+
+macro_rules! impl_step {
+    ($($ty:ident)*) => {$(
+        impl CopyStep for $ty {
+            fn checked_successor(&self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn checked_predecessor(&self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+        }
+    )*};
+}
+
+impl_step!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+impl CopyStep for char {
+    fn checked_successor(&self) -> Option<Self> {
+        match *self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            c => char::from_u32(u32::from(c) + 1),
+        }
+    }
+
+    fn checked_predecessor(&self) -> Option<Self> {
+        match *self {
+            '\u{E000}' => Some('\u{D7FF}'),
+            c => char::from_u32(u32::from(c).checked_sub(1)?),
+        }
+    }
+}